@@ -1,38 +1,102 @@
 use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+use anchor_lang::solana_program::{
+    hash::{hash, hashv},
+    instruction::{AccountMeta, Instruction},
+    program::invoke_signed,
+};
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked},
+    token_interface::{
+        burn, transfer_checked, Burn, Mint, TokenAccount, TokenInterface, TransferChecked,
+    },
 };
 
 declare_id!("758R2jFfces6Ue5B9rLmRrh8NesiU9dCtDa4bUSBpCMt");
 
-// Proposal creation requirements
-pub const PROPOSAL_DEPOSIT_AMOUNT: u64 = 100_000_000;
-pub const MAX_ACTIVE_PROPOSALS: u8 = 3;
-pub const MIN_STAKE_TO_PROPOSE: u64 = 100_000_000; // 100 ZSNIPE (testing)
-pub const MAX_STAKE_DURATION_TO_PROPOSE: i64 = 0 * 86400; // 0 days (testing)
+#[cfg(all(feature = "devnet", feature = "mainnet"))]
+compile_error!("the `devnet` and `mainnet` features are mutually exclusive");
+#[cfg(not(any(feature = "devnet", feature = "mainnet")))]
+compile_error!("enable either the `devnet` or the `mainnet` feature");
+#[cfg(all(feature = "mainnet", feature = "testing"))]
+compile_error!("the `testing` feature compresses governance durations and can't ship to mainnet");
+
+// Network-specific governance constants, selected by the `devnet`/`mainnet` features.
+// `devnet` is the default feature; mainnet builds use `--no-default-features`.
+#[cfg(feature = "mainnet")]
+mod network {
+    pub const MIN_STAKE_DURATION_FOR_VOTING: i64 = 30 * 86400; // 30 days
+    pub const VOTE_LOCK_PERIOD: i64 = 7 * 86400; // 7 days
+    pub const MIN_STAKE_DURATION_TO_PROPOSE_DAYS: u32 = 30;
+    pub const MIN_EXECUTION_DELAY_HOURS: u32 = 24;
+    pub const DEFAULT_EXECUTION_DELAY_HOURS: u32 = 48;
+}
+
+#[cfg(feature = "devnet")]
+mod network {
+    pub const MIN_STAKE_DURATION_FOR_VOTING: i64 = 0; // no waiting period on devnet
+    pub const VOTE_LOCK_PERIOD: i64 = 0;
+    pub const MIN_STAKE_DURATION_TO_PROPOSE_DAYS: u32 = 0;
+    pub const MIN_EXECUTION_DELAY_HOURS: u32 = 0;
+    pub const DEFAULT_EXECUTION_DELAY_HOURS: u32 = 0;
+}
+
+pub use network::*;
 
-// Cast vote constants
-pub const MIN_STAKE_DURATION_FOR_VOTING: i64 = 0 * 86400;
-pub const VOTE_LOCK_PERIOD: i64 = 0 * 86400;
+// A mainnet build must never ship with the devnet shortcuts
+#[cfg(feature = "mainnet")]
+const _: () = {
+    assert!(
+        MIN_STAKE_DURATION_FOR_VOTING > 0,
+        "mainnet requires a non-zero MIN_STAKE_DURATION_FOR_VOTING"
+    );
+    assert!(
+        VOTE_LOCK_PERIOD > 0,
+        "mainnet requires a non-zero VOTE_LOCK_PERIOD"
+    );
+    assert!(
+        MIN_STAKE_DURATION_TO_PROPOSE_DAYS > 0,
+        "mainnet requires a non-zero MIN_STAKE_DURATION_TO_PROPOSE_DAYS"
+    );
+    assert!(
+        MIN_EXECUTION_DELAY_HOURS > 0 && DEFAULT_EXECUTION_DELAY_HOURS >= MIN_EXECUTION_DELAY_HOURS,
+        "mainnet requires a non-zero execution timelock"
+    );
+    assert!(
+        SECONDS_PER_HOUR == 3600,
+        "mainnet requires uncompressed governance durations"
+    );
+};
 
 // String length limits
 pub const MAX_TITLE_LENGTH: usize = 100;
 pub const MAX_DESCRIPTION_LENGTH: usize = 1000;
 pub const MAX_EXECUTION_DATA_LENGTH: usize = 500;
-
-// Voting configuration parameters
-pub const VOTING_PERIOD_3_DAYS: u8 = 0; // 5 minutes
-pub const VOTING_PERIOD_7_DAYS: u8 = 1; // 15 minutes
-pub const VOTING_PERIOD_14_DAYS: u8 = 2; // 25 minutes
-pub const VALID_VOTING_PERIODS: [u8; 3] = [
-    VOTING_PERIOD_3_DAYS,
-    VOTING_PERIOD_7_DAYS,
-    VOTING_PERIOD_14_DAYS,
-];
-pub const QUORUM_PERCENTAGE: u64 = 10;
-pub const PASSING_THRESHOLD_PERCENTAGE: u64 = 51;
-pub const TIME_LOCK_DURATION: i64 = 0 * 86400;
+pub const MAX_METADATA_URI_LENGTH: usize = 200;
+
+// Execution payload encoding: [version, borsh(ExecutionPayload)]
+pub const EXECUTION_PAYLOAD_VERSION: u8 = 1;
+
+// Custom instruction proposal limits
+pub const MAX_PROPOSAL_INSTRUCTIONS: usize = 8;
+pub const MAX_INSTRUCTION_ACCOUNTS: usize = 16;
+pub const MAX_INSTRUCTION_DATA_LENGTH: usize = 512;
+
+// Governance durations are configured in hours; the `testing` feature compresses
+// each hour into one minute so full proposal lifecycles can be exercised on devnet
+#[cfg(not(feature = "testing"))]
+pub const SECONDS_PER_HOUR: i64 = 3600;
+#[cfg(feature = "testing")]
+pub const SECONDS_PER_HOUR: i64 = 60;
+
+// Governance config parameters
+pub const BASIS_POINTS_DENOMINATOR: u64 = 10_000;
+pub const MAX_BATCH_UPDATES: usize = 10;
+pub const MAX_SPAM_PREVENTION_COOLDOWN_HOURS: u32 = 168;
+pub const MAX_PROPOSAL_SPONSORS: u8 = 10;
+pub const PROPOSAL_TYPE_COUNT: usize = 5;
+pub const MAX_VOTE_EXTENSIONS: u8 = 5;
+pub const MAX_UNREVEALED_VOTE_PENALTY_HOURS: u32 = 720;
 
 // Seeds
 pub const PROPOSAL_SEED: &[u8] = b"proposalV1";
@@ -43,8 +107,11 @@ pub const PROGRAM_AUTHORITY_SEED: &[u8] = b"program_authorityV1";
 pub const USER_STAKE_SEED: &[u8] = b"user_stakeV1";
 pub const STAKING_POOL_ESCROW_SEED: &[u8] = b"escrowV1";
 pub const GOVERNANCE_SEED: &[u8] = b"governanceV1";
-pub const GOVERNANCE_CONFIG_SEED: &[u8] = b"governance_configV1";
+pub const GOVERNANCE_CONFIG_SEED: &[u8] = b"governance_configV2";
 pub const TREASURY_SEED: &[u8] = b"treasuryV1";
+pub const PROPOSAL_INSTRUCTIONS_SEED: &[u8] = b"proposal_instructionsV1";
+pub const PROPOSAL_SUMMARY_SEED: &[u8] = b"proposal_summaryV1";
+pub const SPONSORSHIP_SEED: &[u8] = b"sponsorshipV1";
 
 // Governance Enums
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
@@ -52,6 +119,8 @@ pub enum ProposalType {
     Text = 0,
     TreasuryTransfer = 1,
     ParameterUpdate = 2,
+    CustomInstructions = 3,
+    Emergency = 4,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
@@ -63,6 +132,9 @@ pub enum ProposalStatus {
     Cancelled = 4,
     ExecutionFailed = 5,
     EmergencyCancelled = 6,
+    Expired = 7,
+    Vetoed = 8,
+    Draft = 9,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
@@ -70,6 +142,300 @@ pub enum VoteChoice {
     Yes = 0,
     No = 1,
     Abstain = 2,
+    NoWithVeto = 3,
+}
+
+/// How Abstain votes are counted when a proposal is finalized.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum AbstainPolicy {
+    /// Abstain counts toward quorum but not the passing threshold
+    #[default]
+    QuorumOnly = 0,
+    /// Abstain counts toward quorum and the threshold, where it weighs like No
+    QuorumAndThreshold = 1,
+    /// Abstain counts toward neither
+    Excluded = 2,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ProposalThresholdMode {
+    /// Fixed token amount (`base_proposal_stake`)
+    AbsoluteStake = 0,
+    /// Basis-point share of the total staked voting power
+    VotingPowerShare = 1,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub enum ProposalContent {
+    /// Full description stored on the proposal account
+    Inline { description: String },
+    /// Description hosted off-chain (IPFS/Arweave), pinned by its SHA-256 hash
+    Offchain { uri: String, content_hash: [u8; 32] },
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub enum ExecutionPayload {
+    Text { metadata: Vec<u8> },
+    TreasuryTransfer { recipient: Pubkey, amount: u64 },
+    ParameterUpdate { update: ParameterUpdateType },
+    CustomInstructions,
+    Emergency,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub enum ParameterUpdateType {
+    QuorumThreshold { percentage: u16 },
+    ProposalStake { amount: u64 },
+    TimeMultiplier { tier: u8, multiplier: u16 },
+    VotingPeriod { hours: u32 },
+    EmergencySettings { quorum_reduction: u16, voting_period: u32 },
+    TreasuryLimit { percentage: u16 },
+    ExecutionDelay { hours: u32 },
+    PassingThreshold { percentage: u16 },
+    SpamCooldown { hours: u32 },
+    VetoThreshold { percentage: u16 },
+    OptimisticObjectionThreshold { percentage: u16 },
+    ProposalThreshold { mode: ProposalThresholdMode, percentage: u16 },
+    QuorumDecayFloor { percentage: u16 },
+    VoteExtension { hours: u32, max_extensions: u8 },
+    PrivateVoting { reveal_period_hours: u32, unrevealed_penalty_hours: u32 },
+    TypeRules { proposal_type: ProposalType, rules: ProposalTypeRules },
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ParameterBounds {
+    pub min_quorum_percentage: u16,              // 2 bytes
+    pub max_quorum_percentage: u16,              // 2 bytes
+    pub min_proposal_stake_base: u64,            // 8 bytes
+    pub max_proposal_stake_base: u64,            // 8 bytes
+    pub min_voting_period_hours: u32,            // 4 bytes
+    pub max_voting_period_hours: u32,            // 4 bytes
+    pub min_execution_delay_hours: u32,          // 4 bytes
+    pub max_execution_delay_hours: u32,          // 4 bytes
+    pub min_stake_duration_days: u32,            // 4 bytes
+    pub max_treasury_withdrawal_percentage: u16, // 2 bytes
+    pub spam_prevention_cooldown_hours: u32,     // 4 bytes
+    pub reserved: [u8; 74],                      // 74 bytes
+}
+// Total: 120 bytes
+
+impl Default for ParameterBounds {
+    fn default() -> Self {
+        Self {
+            min_quorum_percentage: 1000, // 10%
+            max_quorum_percentage: 7000, // 70%
+            min_proposal_stake_base: 1000 * 10_u64.pow(6),
+            max_proposal_stake_base: 100000 * 10_u64.pow(6),
+            min_voting_period_hours: 24,
+            max_voting_period_hours: 168,
+            min_execution_delay_hours: MIN_EXECUTION_DELAY_HOURS,
+            max_execution_delay_hours: 72,
+            min_stake_duration_days: MIN_STAKE_DURATION_TO_PROPOSE_DAYS,
+            max_treasury_withdrawal_percentage: 1000, // 10%
+            spam_prevention_cooldown_hours: 24,
+            reserved: [0; 74],
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct TimeMultipliers {
+    pub tier_1_days: u32,       // 4 bytes
+    pub tier_1_multiplier: u16, // 2 bytes
+    pub tier_2_days: u32,       // 4 bytes
+    pub tier_2_multiplier: u16, // 2 bytes
+    pub tier_3_multiplier: u16, // 2 bytes
+    pub tier_3_days: u16,       // 2 bytes
+}
+// Total: 16 bytes
+
+impl Default for TimeMultipliers {
+    fn default() -> Self {
+        Self {
+            tier_1_days: 30,
+            tier_1_multiplier: 120, // 1.2x
+            tier_2_days: 90,
+            tier_2_multiplier: 150, // 1.5x
+            tier_3_multiplier: 200, // 2.0x
+            tier_3_days: 365,
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ProposalSettings {
+    pub base_proposal_stake: u64,             // 8 bytes
+    pub stake_escalation_factor: u16,         // 2 bytes
+    pub max_escalation_multiplier: u16,       // 2 bytes
+    pub default_quorum_percentage: u16,       // 2 bytes
+    pub default_voting_period_hours: u32,     // 4 bytes
+    pub proposal_fee_percentage: u16,         // 2 bytes
+    pub refund_threshold_percentage: u16,     // 2 bytes
+    pub max_concurrent_proposals: u8,         // 1 byte
+    pub passing_threshold_percentage: u16,    // 2 bytes
+    pub veto_threshold_percentage: u16,       // 2 bytes
+    pub burn_vetoed_deposits: bool,           // 1 byte
+    pub optimistic_objection_percentage: u16, // 2 bytes
+    pub max_optimistic_treasury_amount: u64,  // 8 bytes
+    pub proposal_threshold_mode: ProposalThresholdMode, // 1 byte
+    pub proposal_threshold_percentage: u16,   // 2 bytes
+    pub quorum_decay_floor_percentage: u16,   // 2 bytes
+    pub vote_extension_hours: u32,            // 4 bytes
+    pub max_vote_extensions: u8,              // 1 byte
+    pub reveal_period_hours: u32,             // 4 bytes
+    pub unrevealed_vote_penalty_hours: u32,   // 4 bytes
+    pub reserved: [u8; 8],                    // 8 bytes
+}
+// Total: 64 bytes
+
+impl Default for ProposalSettings {
+    fn default() -> Self {
+        Self {
+            base_proposal_stake: 5000 * 10_u64.pow(6),
+            stake_escalation_factor: 150,
+            max_escalation_multiplier: 500,
+            default_quorum_percentage: 2000,       // 20%
+            default_voting_period_hours: 72,       // 3 days
+            proposal_fee_percentage: 100,          // 1%
+            refund_threshold_percentage: 3000,     // 30%
+            max_concurrent_proposals: 3,
+            passing_threshold_percentage: 5100,    // 51%
+            veto_threshold_percentage: 3340,       // 33.4%
+            burn_vetoed_deposits: false,           // slashed deposits go to the treasury
+            optimistic_objection_percentage: 1000, // 10% of total voting power
+            max_optimistic_treasury_amount: 10000 * 10_u64.pow(6),
+            proposal_threshold_mode: ProposalThresholdMode::AbsoluteStake,
+            proposal_threshold_percentage: 50,     // 0.5% of total voting power
+            quorum_decay_floor_percentage: 0,      // no decay
+            vote_extension_hours: 12,
+            max_vote_extensions: 2,
+            reveal_period_hours: 24,
+            unrevealed_vote_penalty_hours: 168,    // 7 extra days of stake lock
+            reserved: [0; 8],
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ExecutionSettings {
+    pub treasury_threshold_for_multisig: u64, // 8 bytes
+    pub multisig_required_approvals: u8,      // 1 byte
+    pub auto_execution_enabled: bool,         // 1 byte
+    pub execution_grace_period_hours: u32,    // 4 bytes
+    pub execution_delay_hours: u32,           // 4 bytes
+    pub proposal_archival_period_hours: u32,  // 4 bytes
+    pub reserved: [u8; 10],                   // 10 bytes
+}
+// Total: 32 bytes
+
+impl Default for ExecutionSettings {
+    fn default() -> Self {
+        Self {
+            treasury_threshold_for_multisig: 100000 * 10_u64.pow(6),
+            multisig_required_approvals: 3,
+            auto_execution_enabled: true,
+            execution_grace_period_hours: 48,
+            execution_delay_hours: DEFAULT_EXECUTION_DELAY_HOURS,
+            proposal_archival_period_hours: 720, // 30 days
+            reserved: [0; 10],
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct EmergencySettings {
+    pub emergency_multisig: Pubkey,         // 32 bytes
+    pub emergency_override_enabled: bool,   // 1 byte
+    pub emergency_quorum_reduction: u16,    // 2 bytes
+    pub emergency_voting_period_hours: u32, // 4 bytes
+    pub emergency_cooldown_hours: u32,      // 4 bytes
+    pub emergency_activated_at: i64,        // 8 bytes
+    pub reserved: [u8; 1],                  // 1 byte
+}
+// Total: 52 bytes
+
+impl Default for EmergencySettings {
+    fn default() -> Self {
+        Self {
+            emergency_multisig: Pubkey::default(),
+            emergency_override_enabled: false,
+            emergency_quorum_reduction: 1000,  // 10% reduction
+            emergency_voting_period_hours: 12, // 12 hours
+            emergency_cooldown_hours: 168,     // 7 days
+            emergency_activated_at: 0,
+            reserved: [0; 1],
+        }
+    }
+}
+
+/// Per-`ProposalType` overrides of the global governance settings. A zero field
+/// inherits the global value; see `GovernanceConfig::rules_for`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct ProposalTypeRules {
+    pub quorum_percentage: u16,            // 2 bytes
+    pub passing_threshold_percentage: u16, // 2 bytes
+    pub execution_delay_hours: u32,        // 4 bytes
+    pub min_voting_period_hours: u32,      // 4 bytes
+    pub deposit_percentage: u16,           // 2 bytes
+    pub abstain_policy: AbstainPolicy,     // 1 byte
+}
+// Total: 15 bytes
+
+impl ProposalTypeRules {
+    /// Default table, indexed by `ProposalType`. Proposals that move funds or run
+    /// arbitrary instructions need more turnout, a wider margin and a longer timelock.
+    pub fn defaults() -> [Self; PROPOSAL_TYPE_COUNT] {
+        let inherit = Self::default();
+        let fund_moving = Self {
+            quorum_percentage: 3000,            // 30%
+            passing_threshold_percentage: 6000, // 60%
+            execution_delay_hours: DEFAULT_EXECUTION_DELAY_HOURS * 3 / 2,
+            min_voting_period_hours: 72,
+            deposit_percentage: 200, // 2%
+            abstain_policy: AbstainPolicy::QuorumOnly,
+        };
+
+        let parameter_update = Self {
+            quorum_percentage: 2500, // 25%
+            ..fund_moving
+        };
+        let custom_instructions = Self {
+            passing_threshold_percentage: 6600, // 66%
+            ..fund_moving
+        };
+
+        // Text, TreasuryTransfer, ParameterUpdate, CustomInstructions, Emergency
+        [
+            inherit,
+            fund_moving,
+            parameter_update,
+            custom_instructions,
+            inherit,
+        ]
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ProposalValidationResult {
+    pub required_stake: u64,
+    pub has_sufficient_stake: bool,
+    pub meets_duration_requirement: bool,
+    pub can_propose: bool,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct InstructionAccountMeta {
+    pub pubkey: Pubkey,
+    pub is_signer: bool,
+    pub is_writable: bool,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct ProposalInstruction {
+    pub program_id: Pubkey,
+    pub accounts: Vec<InstructionAccountMeta>,
+    pub data: Vec<u8>,
+    pub executed_at: i64,
 }
 
 #[program]
@@ -165,16 +531,28 @@ pub mod zero_sided_snipe {
             ErrorCode::InsufficientStakedBalance
         );
 
-        if let Some(governance_account) = &ctx.accounts.governance_account {
+        // An empty governance PDA means the staker never joined governance, so nothing
+        // can be locked or pledged. Accounts that predate `migrate_governance_account`
+        // are read with their missing fields zeroed, i.e. without pledges.
+        let governance_info = &ctx.accounts.governance_account;
+        if !governance_info.data_is_empty() {
+            let data = governance_info.try_borrow_data()?;
+            let governance_account = GovernanceAccount::try_deserialize_legacy(&data)?;
             let clock = Clock::get()?;
-            require!(
-                clock.unix_timestamp >= governance_account.stake_lock_end,
+            require_gte!(
+                clock.unix_timestamp,
+                governance_account.stake_lock_end,
+                ErrorCode::TokensLockedForGovernance
+            );
+            require_gte!(
+                user_staking_account.staked_amount - amount,
+                governance_account.pledged_stake,
                 ErrorCode::TokensLockedForGovernance
             );
         }
 
         let authority_bump = &[staking_pool.authority_bump];
-        let authority_seeds = &[PROGRAM_AUTHORITY_SEED.as_ref(), authority_bump.as_ref()];
+        let authority_seeds = &[PROGRAM_AUTHORITY_SEED, authority_bump.as_ref()];
         let signer_seeds = &[&authority_seeds[..]];
 
         transfer_checked(
@@ -217,6 +595,12 @@ pub mod zero_sided_snipe {
         governance_account.voting_power_cache = 0;
         governance_account.created_at = clock.unix_timestamp;
         governance_account.bump = ctx.bumps.governance_account;
+        governance_account.active_proposals = 0;
+        governance_account.proposals_created = 0;
+        governance_account.proposals_failed = 0;
+        governance_account.proposals_slashed = 0;
+        governance_account.last_proposal_timestamp = 0;
+        governance_account.pledged_stake = 0;
 
         Ok(())
     }
@@ -226,41 +610,255 @@ pub mod zero_sided_snipe {
         let clock = Clock::get()?;
 
         governance_config.authority = ctx.accounts.authority.key();
-        governance_config.quorum_percentage = QUORUM_PERCENTAGE;
-        governance_config.passing_threshold = PASSING_THRESHOLD_PERCENTAGE;
-        governance_config.timelock_duration = TIME_LOCK_DURATION;
-        governance_config.min_stake_to_propose = MIN_STAKE_TO_PROPOSE;
-        governance_config.proposal_deposit = PROPOSAL_DEPOSIT_AMOUNT;
+        governance_config.staking_pool = ctx.accounts.staking_pool.key();
+        governance_config.version = 1;
         governance_config.created_at = clock.unix_timestamp;
         governance_config.last_updated = clock.unix_timestamp;
         governance_config.bump = ctx.bumps.governance_config;
-        governance_config.reserved = [0; 32];
+        governance_config.next_proposal_id = 0;
 
-        msg!("✅ Governance config initialized");
-        msg!("   Authority: {}", ctx.accounts.authority.key());
-        msg!("   Quorum: {}%", governance_config.quorum_percentage);
-        msg!("   Threshold: {}%", governance_config.passing_threshold);
-        msg!(
-            "   Timelock: {} seconds",
-            governance_config.timelock_duration
+        governance_config.parameter_bounds = ParameterBounds::default();
+        governance_config.time_multipliers = TimeMultipliers::default();
+        governance_config.proposal_settings = ProposalSettings::default();
+        governance_config.execution_settings = ExecutionSettings::default();
+        governance_config.emergency_settings = EmergencySettings::default();
+        governance_config.proposal_type_rules = ProposalTypeRules::defaults();
+
+        emit!(GovernanceConfigInitialized {
+            authority: ctx.accounts.authority.key(),
+            staking_pool: ctx.accounts.staking_pool.key(),
+            timestamp: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    pub fn update_governance_parameter(
+        ctx: Context<UpdateGovernanceParameter>,
+        update_type: ParameterUpdateType,
+    ) -> Result<()> {
+        let governance_config = &mut ctx.accounts.governance_config;
+        let clock = Clock::get()?;
+
+        let is_admin = governance_config.authority == ctx.accounts.authority.key();
+        let is_governance = ctx.accounts.governance_proposal.is_some();
+
+        require!(is_admin || is_governance, ErrorCode::Unauthorized);
+
+        let Some(proposal) = ctx.accounts.governance_proposal.as_mut() else {
+            apply_parameter_update(
+                governance_config,
+                update_type,
+                ctx.accounts.authority.key(),
+            )?;
+            return governance_config.validate_invariants();
+        };
+
+        // Governance path: the update must be exactly what the passed proposal voted on
+        require!(
+            proposal.proposal_type == ProposalType::ParameterUpdate,
+            ErrorCode::InvalidProposalType
+        );
+        require_proposal_executable(
+            proposal,
+            governance_config.execution_settings.execution_grace_period_hours,
+            clock.unix_timestamp,
+        )?;
+
+        let payload = ExecutionPayload::decode(&proposal.execution_data)?;
+        require!(
+            payload == ExecutionPayload::ParameterUpdate {
+                update: update_type.clone(),
+            },
+            ErrorCode::ParameterUpdateMismatch
+        );
+
+        let deposit_escrow_account = ctx
+            .accounts
+            .deposit_escrow_account
+            .as_ref()
+            .ok_or(ErrorCode::MissingDepositAccounts)?;
+        let proposer_token_account = ctx
+            .accounts
+            .proposer_token_account
+            .as_ref()
+            .ok_or(ErrorCode::MissingDepositAccounts)?;
+        let deposit_token_mint = ctx
+            .accounts
+            .deposit_token_mint
+            .as_ref()
+            .ok_or(ErrorCode::MissingDepositAccounts)?;
+        let token_program = ctx
+            .accounts
+            .token_program
+            .as_ref()
+            .ok_or(ErrorCode::MissingDepositAccounts)?;
+        let staking_pool = ctx
+            .accounts
+            .staking_pool
+            .as_ref()
+            .ok_or(ErrorCode::MissingDepositAccounts)?;
+        let program_authority = ctx
+            .accounts
+            .program_authority
+            .as_ref()
+            .ok_or(ErrorCode::MissingDepositAccounts)?;
+
+        require!(
+            proposer_token_account.owner == proposal.proposer,
+            ErrorCode::InvalidProposerAccount
+        );
+
+        apply_parameter_update(governance_config, update_type, proposal.key())?;
+        governance_config.validate_invariants()?;
+
+        proposal.status = ProposalStatus::Executed;
+        proposal.executed_at = clock.unix_timestamp;
+
+        transfer_deposit_to_proposer(
+            deposit_escrow_account,
+            proposer_token_account,
+            deposit_token_mint,
+            program_authority,
+            token_program,
+            proposal.deposit_amount,
+            staking_pool.authority_bump,
+        )?;
+
+        proposal.deposit_refunded = true;
+
+        Ok(())
+    }
+
+    pub fn validate_proposal_requirements(
+        ctx: Context<ValidateProposalRequirements>,
+    ) -> Result<ProposalValidationResult> {
+        let governance_config = &ctx.accounts.governance_config;
+        let user_staking_account = &ctx.accounts.user_staking_account;
+        let user_governance_account = &ctx.accounts.user_governance_account;
+
+        let required_stake = governance_config.calculate_proposal_stake_requirement(
+            user_governance_account.proposer_history(),
+            ctx.accounts.staking_pool.total_voting_power(),
+        )?;
+
+        let has_sufficient_stake = user_staking_account.staked_amount >= required_stake;
+
+        let clock = Clock::get()?;
+        let stake_duration_days =
+            ((clock.unix_timestamp - user_staking_account.timestamp) / 86400) as u32;
+        let meets_duration_requirement =
+            stake_duration_days >= governance_config.parameter_bounds.min_stake_duration_days;
+
+        let result = ProposalValidationResult {
+            required_stake,
+            has_sufficient_stake,
+            meets_duration_requirement,
+            can_propose: has_sufficient_stake && meets_duration_requirement,
+        };
+
+        emit!(ProposalValidationPerformed {
+            user: ctx.accounts.user.key(),
+            required_stake,
+            has_sufficient_stake,
+            meets_duration_requirement,
+            timestamp: clock.unix_timestamp,
+        });
+
+        Ok(result)
+    }
+
+    pub fn toggle_emergency_mode(ctx: Context<ToggleEmergencyMode>, enabled: bool) -> Result<()> {
+        let governance_config = &mut ctx.accounts.governance_config;
+
+        require!(
+            governance_config.authority == ctx.accounts.authority.key()
+                || governance_config.emergency_settings.emergency_multisig
+                    == ctx.accounts.authority.key(),
+            ErrorCode::EmergencyNotAuthorized
         );
 
+        let clock = Clock::get()?;
+
+        if enabled {
+            // Each activation lasts one cooldown window and must be followed by a full
+            // cooldown window off, so emergency mode can't be chained indefinitely
+            let settings = &governance_config.emergency_settings;
+            let cooldown_seconds = settings.emergency_cooldown_hours as i64 * SECONDS_PER_HOUR;
+            require!(
+                settings.emergency_activated_at == 0
+                    || clock.unix_timestamp
+                        >= settings.emergency_activated_at + 2 * cooldown_seconds,
+                ErrorCode::EmergencyCooldownActive
+            );
+
+            governance_config
+                .emergency_settings
+                .emergency_activated_at = clock.unix_timestamp;
+        }
+
+        governance_config
+            .emergency_settings
+            .emergency_override_enabled = enabled;
+        governance_config.last_updated = clock.unix_timestamp;
+
+        emit!(EmergencyModeToggled {
+            enabled,
+            triggered_by: ctx.accounts.authority.key(),
+            timestamp: clock.unix_timestamp,
+        });
+
         Ok(())
     }
 
+    pub fn batch_update_parameters(
+        ctx: Context<UpdateGovernanceParameter>,
+        updates: Vec<ParameterUpdateType>,
+    ) -> Result<()> {
+        let governance_config = &mut ctx.accounts.governance_config;
+
+        require!(
+            governance_config.authority == ctx.accounts.authority.key(),
+            ErrorCode::Unauthorized
+        );
+        require!(
+            updates.len() <= MAX_BATCH_UPDATES,
+            ErrorCode::TooManyBatchUpdates
+        );
+
+        for update in updates {
+            apply_parameter_update(governance_config, update, ctx.accounts.authority.key())?;
+        }
+
+        // Relationships between parameters are only checked once the whole batch has landed
+        governance_config.validate_invariants()
+    }
+
     pub fn calculate_voting_power(ctx: Context<CalculateVotingPower>) -> Result<u64> {
         let user_staking_account = &ctx.accounts.user_staking_account;
         let governance_account = &mut ctx.accounts.governance_account;
+        let governance_config = &ctx.accounts.governance_config;
         let clock = Clock::get()?;
 
         let stake_duration_seconds = clock.unix_timestamp - user_staking_account.timestamp;
         let stake_duration_days = (stake_duration_seconds / 86400) as u32;
 
-        let voting_power =
-            calculate_hybrid_voting_power(user_staking_account.staked_amount, stake_duration_days);
+        let voting_power = calculate_hybrid_voting_power(
+            user_staking_account.staked_amount,
+            stake_duration_days,
+            &governance_config.time_multipliers,
+        );
 
         governance_account.voting_power_cache = voting_power;
 
+        emit!(VotingPowerCalculated {
+            user: ctx.accounts.staker.key(),
+            voting_power,
+            stake_amount: user_staking_account.staked_amount,
+            stake_duration_days,
+            timestamp: clock.unix_timestamp,
+        });
+
         Ok(voting_power)
     }
 
@@ -273,47 +871,187 @@ pub mod zero_sided_snipe {
         Ok(())
     }
 
+    pub fn create_proposal_instructions(
+        ctx: Context<CreateProposalInstructions>,
+        proposal_id: u64,
+        instructions: Vec<ProposalInstruction>,
+    ) -> Result<()> {
+        let proposal_instructions = &mut ctx.accounts.proposal_instructions;
+        let staking_pool = &ctx.accounts.staking_pool;
+
+        require!(
+            !instructions.is_empty() && instructions.len() <= MAX_PROPOSAL_INSTRUCTIONS,
+            ErrorCode::InvalidInstructionCount
+        );
+
+        for instruction in instructions.iter() {
+            require!(
+                instruction.accounts.len() <= MAX_INSTRUCTION_ACCOUNTS,
+                ErrorCode::TooManyInstructionAccounts
+            );
+            require!(
+                instruction.data.len() <= MAX_INSTRUCTION_DATA_LENGTH,
+                ErrorCode::InstructionDataTooLarge
+            );
+
+            for meta in instruction.accounts.iter() {
+                // Only the program authority PDA can sign for a governance instruction
+                require!(
+                    !meta.is_signer || meta.pubkey == staking_pool.authority,
+                    ErrorCode::InvalidInstructionSigner
+                );
+                // Staked tokens and proposal deposits are never governable
+                require!(
+                    meta.pubkey != staking_pool.escrow_account
+                        && meta.pubkey != ctx.accounts.deposit_escrow_account.key(),
+                    ErrorCode::ProtectedAccountInInstruction
+                );
+            }
+        }
+
+        proposal_instructions.proposal_id = proposal_id;
+        proposal_instructions.proposer = ctx.accounts.proposer.key();
+        proposal_instructions.instructions = instructions
+            .into_iter()
+            .map(|instruction| ProposalInstruction {
+                executed_at: 0,
+                ..instruction
+            })
+            .collect();
+        proposal_instructions.executed_count = 0;
+        proposal_instructions.bump = ctx.bumps.proposal_instructions;
+
+        msg!(
+            "✅ {} instruction(s) stored for proposal #{}",
+            proposal_instructions.instructions.len(),
+            proposal_id
+        );
+
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments)]
     pub fn create_proposal(
         ctx: Context<CreateProposal>,
         proposal_id: u64,
         title: String,
-        description: String,
+        content: ProposalContent,
         proposal_type: ProposalType,
         execution_data: Vec<u8>,
-        voting_period: u8,
+        voting_period_hours: u32,
+        optimistic: bool,
+        draft: bool,
+        private_voting: bool,
     ) -> Result<()> {
         let proposer_staking = &ctx.accounts.proposer_staking_account;
+        let proposer_governance = &mut ctx.accounts.proposer_governance_account;
+        let governance_config = &mut ctx.accounts.governance_config;
         let proposal = &mut ctx.accounts.proposal_account;
         let clock = Clock::get()?;
 
+        // Ids are allocated in order and never reused, so a closed proposal's id (and
+        // the summary or instructions PDAs derived from it) can't be taken again
         require!(
-            proposer_staking.staked_amount >= MIN_STAKE_TO_PROPOSE,
-            ErrorCode::InsufficientStakeToPropose
+            proposal_id == governance_config.next_proposal_id,
+            ErrorCode::InvalidProposalId
         );
 
+        let required_stake = governance_config.calculate_proposal_stake_requirement(
+            proposer_governance.proposer_history(),
+            ctx.accounts.staking_pool.total_voting_power(),
+        )?;
+        // Stake already backing other proposals or sponsorships can't back this one
+        let available_stake = proposer_staking
+            .staked_amount
+            .saturating_sub(proposer_governance.pledged_stake);
+        // Drafts can be short of the requirement and collect sponsors before activation
+        let backing_stake = if draft {
+            require_gt!(available_stake, 0, ErrorCode::InsufficientStakeToPropose);
+            available_stake.min(required_stake)
+        } else {
+            require_gte!(
+                available_stake,
+                required_stake,
+                ErrorCode::InsufficientStakeToPropose
+            );
+            required_stake
+        };
+
         let stake_duration = clock.unix_timestamp - proposer_staking.timestamp;
-        require!(
-            stake_duration >= MAX_STAKE_DURATION_TO_PROPOSE,
+        let min_stake_duration =
+            governance_config.parameter_bounds.min_stake_duration_days as i64 * 86400;
+        require_gte!(
+            stake_duration,
+            min_stake_duration,
             ErrorCode::InsufficientStakeDurationToPropose
         );
 
-        require!(
-            title.len() <= MAX_TITLE_LENGTH,
-            ErrorCode::ProposalTitleTooLong
-        );
-        require!(
-            description.len() <= MAX_DESCRIPTION_LENGTH,
-            ErrorCode::ProposalDescriptionTooLong
-        );
-        require!(
-            execution_data.len() <= MAX_EXECUTION_DATA_LENGTH,
-            ErrorCode::ExecutionDataTooLarge
+        require_gt!(
+            governance_config.proposal_settings.max_concurrent_proposals,
+            proposer_governance.active_proposals,
+            ErrorCode::MaxActiveProposalsReached
         );
+
+        let spam_cooldown = governance_config
+            .parameter_bounds
+            .spam_prevention_cooldown_hours as i64
+            * SECONDS_PER_HOUR;
+        let cooldown_ends_at = proposer_governance.last_proposal_timestamp + spam_cooldown;
         require!(
-            VALID_VOTING_PERIODS.contains(&voting_period),
-            ErrorCode::InvalidVotingPeriod
+            proposer_governance.last_proposal_timestamp == 0
+                || clock.unix_timestamp >= cooldown_ends_at,
+            ErrorCode::ProposalCooldownActive
         );
 
+        let (description, metadata_uri, content_hash) = validate_proposal_content(
+            governance_config,
+            &title,
+            content,
+            proposal_type,
+            &execution_data,
+            optimistic,
+        )?;
+        let rules = governance_config.rules_for(proposal_type);
+        // Emergencies always run for the configured emergency period, whatever was passed
+        let voting_period_hours = if proposal_type == ProposalType::Emergency {
+            governance_config.emergency_settings.emergency_voting_period_hours
+        } else {
+            require!(
+                voting_period_hours >= rules.min_voting_period_hours
+                    && voting_period_hours
+                        <= governance_config.parameter_bounds.max_voting_period_hours,
+                ErrorCode::InvalidVotingPeriod
+            );
+            voting_period_hours
+        };
+
+        if proposal_type == ProposalType::Emergency {
+            require!(
+                governance_config.is_emergency_active(clock.unix_timestamp),
+                ErrorCode::EmergencyModeNotActive
+            );
+            // Emergencies can't wait out a reveal phase
+            require!(!private_voting, ErrorCode::PrivateVotingNotAllowed);
+        }
+
+        if matches!(
+            proposal_type,
+            ProposalType::CustomInstructions | ProposalType::Emergency
+        ) {
+            let proposal_instructions = ctx
+                .accounts
+                .proposal_instructions
+                .as_ref()
+                .ok_or(ErrorCode::MissingProposalInstructions)?;
+            require!(
+                proposal_instructions.proposer == ctx.accounts.proposer.key(),
+                ErrorCode::InvalidProposalInstructions
+            );
+        }
+
+        let deposit_amount =
+            governance_config.calculate_proposal_deposit(proposal_type, backing_stake)?;
+
         transfer_checked(
             CpiContext::new(
                 ctx.accounts.token_program.to_account_info(),
@@ -324,24 +1062,37 @@ pub mod zero_sided_snipe {
                     authority: ctx.accounts.proposer.to_account_info(),
                 },
             ),
-            PROPOSAL_DEPOSIT_AMOUNT,
+            deposit_amount,
             ctx.accounts.deposit_token_mint.decimals,
         )?;
 
-        let voting_duration = get_voting_duration_seconds(voting_period)?;
-        let voting_ends_at = clock
-            .unix_timestamp
-            .checked_add(voting_duration)
-            .ok_or(ErrorCode::InvalidAmount)?;
+        // Drafts start the voting clock in activate_proposal
+        let voting_ends_at = if draft {
+            0
+        } else {
+            clock
+                .unix_timestamp
+                .checked_add(voting_period_hours as i64 * SECONDS_PER_HOUR)
+                .ok_or(ErrorCode::InvalidAmount)?
+        };
+        let reveal_ends_at = if private_voting && !draft {
+            governance_config.reveal_ends_at(voting_ends_at)?
+        } else {
+            0
+        };
 
         proposal.proposal_id = proposal_id;
         proposal.proposer = ctx.accounts.proposer.key();
         proposal.title = title;
         proposal.description = description;
         proposal.proposal_type = proposal_type;
-        proposal.status = ProposalStatus::Active;
+        proposal.status = if draft {
+            ProposalStatus::Draft
+        } else {
+            ProposalStatus::Active
+        };
         proposal.execution_data = execution_data;
-        proposal.voting_period_days = voting_period;
+        proposal.voting_period_hours = voting_period_hours;
         proposal.created_at = clock.unix_timestamp;
         proposal.voting_ends_at = voting_ends_at;
         proposal.finalized_at = 0;
@@ -351,74 +1102,113 @@ pub mod zero_sided_snipe {
         proposal.no_votes = 0;
         proposal.abstain_votes = 0;
         proposal.total_voters = 0;
-        proposal.deposit_amount = PROPOSAL_DEPOSIT_AMOUNT;
+        proposal.last_vote_at = 0;
+        proposal.deposit_amount = deposit_amount;
         proposal.deposit_refunded = false;
         proposal.bump = ctx.bumps.proposal_account;
-        proposal.reserved = [0; 32];
+        proposal.veto_votes = 0;
+        proposal.is_optimistic = optimistic;
+        proposal.metadata_uri = metadata_uri;
+        proposal.content_hash = content_hash;
+        proposal.required_stake = required_stake;
+        proposal.backing_stake = backing_stake;
+        proposal.proposer_pledge = backing_stake;
+        proposal.sponsor_count = 0;
+        proposal.abstain_policy = rules.abstain_policy;
+        proposal.vote_extensions = 0;
+        proposal.is_private = private_voting;
+        proposal.reveal_ends_at = reveal_ends_at;
+        proposal.voting_power_snapshot = if draft {
+            0
+        } else {
+            ctx.accounts.staking_pool.total_voting_power()
+        };
+        proposal.reserved = [0; 3];
+
+        proposer_governance.active_proposals = proposer_governance
+            .active_proposals
+            .checked_add(1)
+            .ok_or(ErrorCode::InvalidAmount)?;
+        proposer_governance.pledged_stake = proposer_governance
+            .pledged_stake
+            .checked_add(backing_stake)
+            .ok_or(ErrorCode::InvalidAmount)?;
+        proposer_governance.proposals_created = proposer_governance
+            .proposals_created
+            .checked_add(1)
+            .ok_or(ErrorCode::InvalidAmount)?;
+        proposer_governance.last_proposal_timestamp = clock.unix_timestamp;
+        governance_config.next_proposal_id = governance_config
+            .next_proposal_id
+            .checked_add(1)
+            .ok_or(ErrorCode::InvalidAmount)?;
 
         Ok(())
     }
 
-    pub fn cast_vote(ctx: Context<CastVote>, vote_choice: VoteChoice) -> Result<()> {
+    /// Records a vote. A vote inside the final extension window that flips the
+    /// pass/fail outcome extends voting by that window, up to `max_vote_extensions`
+    /// times. Remaining accounts may carry (vote_record, governance_account) pairs
+    /// of earlier voters whose stake locks should follow the extension.
+    pub fn cast_vote<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CastVote<'info>>,
+        vote_choice: VoteChoice,
+    ) -> Result<()> {
         let proposal = &mut ctx.accounts.proposal_account;
         let governance_account = &mut ctx.accounts.governance_account;
         let user_staking_account = &ctx.accounts.user_staking_account;
         let vote_record = &mut ctx.accounts.vote_record;
+        let governance_config = &ctx.accounts.governance_config;
         let clock = Clock::get()?;
 
-        require!(
-            proposal.status == ProposalStatus::Active,
-            ErrorCode::ProposalNotActive
-        );
-        require!(
-            clock.unix_timestamp < proposal.voting_ends_at,
-            ErrorCode::VotingPeriodEnded
-        );
-
-        let stake_duration = clock.unix_timestamp - user_staking_account.timestamp;
-        require!(
-            stake_duration >= MIN_STAKE_DURATION_FOR_VOTING,
-            ErrorCode::InsufficientStakeDurationToVote
-        );
-        require!(
-            governance_account.voting_power_cache > 0,
-            ErrorCode::VotingPowerNotCalculated
-        );
+        require!(!proposal.is_private, ErrorCode::PrivateVotingRequired);
+        validate_vote_eligibility(
+            proposal,
+            user_staking_account,
+            governance_account,
+            clock.unix_timestamp,
+        )?;
 
         let voting_power = governance_account.voting_power_cache;
-
-        match vote_choice {
-            VoteChoice::Yes => {
-                proposal.yes_votes = proposal
-                    .yes_votes
-                    .checked_add(voting_power)
-                    .ok_or(ErrorCode::InvalidAmount)?;
-            }
-            VoteChoice::No => {
-                proposal.no_votes = proposal
-                    .no_votes
-                    .checked_add(voting_power)
-                    .ok_or(ErrorCode::InvalidAmount)?;
-            }
-            VoteChoice::Abstain => {
-                proposal.abstain_votes = proposal
-                    .abstain_votes
-                    .checked_add(voting_power)
-                    .ok_or(ErrorCode::InvalidAmount)?;
-            }
+        let total_voting_power = ctx.accounts.staking_pool.total_voting_power();
+        let was_passing =
+            is_passing(proposal, governance_config, total_voting_power, clock.unix_timestamp)?;
+
+        record_vote(proposal, vote_choice, voting_power)?;
+        proposal.last_vote_at = clock.unix_timestamp;
+
+        let settings = &governance_config.proposal_settings;
+        let extension = settings.vote_extension_hours as i64 * SECONDS_PER_HOUR;
+        let in_final_window = clock.unix_timestamp >= proposal.voting_ends_at - extension;
+        if extension > 0
+            && in_final_window
+            && proposal.vote_extensions < settings.max_vote_extensions
+            && is_passing(proposal, governance_config, total_voting_power, clock.unix_timestamp)?
+                != was_passing
+        {
+            // Grow the period along with the end so the voting start stays put
+            proposal.voting_ends_at = proposal
+                .voting_ends_at
+                .checked_add(extension)
+                .ok_or(ErrorCode::InvalidAmount)?;
+            proposal.voting_period_hours = proposal
+                .voting_period_hours
+                .checked_add(settings.vote_extension_hours)
+                .ok_or(ErrorCode::InvalidAmount)?;
+            proposal.vote_extensions += 1;
+
+            extend_vote_locks(proposal, ctx.remaining_accounts, ctx.program_id)?;
+
+            emit!(VotingExtended {
+                proposal_id: proposal.proposal_id,
+                voting_ends_at: proposal.voting_ends_at,
+                vote_extensions: proposal.vote_extensions,
+                triggered_by: ctx.accounts.voter.key(),
+                timestamp: clock.unix_timestamp,
+            });
         }
 
-        proposal.total_voters = proposal
-            .total_voters
-            .checked_add(1)
-            .ok_or(ErrorCode::InvalidAmount)?;
-
-        let lock_end = proposal
-            .voting_ends_at
-            .checked_add(VOTE_LOCK_PERIOD)
-            .ok_or(ErrorCode::InvalidAmount)?;
-
-        governance_account.stake_lock_end = lock_end;
+        extend_stake_lock(governance_account, proposal)?;
         governance_account.last_vote_timestamp = clock.unix_timestamp;
         governance_account.participation_count = governance_account
             .participation_count
@@ -427,10 +1217,11 @@ pub mod zero_sided_snipe {
 
         vote_record.voter = ctx.accounts.voter.key();
         vote_record.proposal_id = proposal.proposal_id;
-        vote_record.vote_choice = vote_choice;
+        vote_record.vote_choice = Some(vote_choice);
         vote_record.voting_power = voting_power;
         vote_record.voted_at = clock.unix_timestamp;
         vote_record.bump = ctx.bumps.vote_record;
+        vote_record.commitment = [0; 32];
 
         Ok(())
     }
@@ -438,12 +1229,10 @@ pub mod zero_sided_snipe {
     pub fn finalize_proposal(ctx: Context<FinalizeProposal>) -> Result<()> {
         let proposal = &mut ctx.accounts.proposal_account;
         let staking_pool = &ctx.accounts.staking_pool;
+        let governance_config = &ctx.accounts.governance_config;
+        let proposer_governance = &mut ctx.accounts.proposer_governance_account;
         let clock = Clock::get()?;
 
-        require!(
-            clock.unix_timestamp >= proposal.voting_ends_at,
-            ErrorCode::VotingPeriodNotEnded
-        );
         require!(
             proposal.status == ProposalStatus::Active,
             ErrorCode::ProposalNotActive
@@ -452,6 +1241,17 @@ pub mod zero_sided_snipe {
             proposal.finalized_at == 0,
             ErrorCode::ProposalAlreadyFinalized
         );
+        // Private votes only reach the tally once revealed
+        require!(
+            !proposal.is_private || clock.unix_timestamp >= proposal.reveal_ends_at,
+            ErrorCode::RevealPeriodActive
+        );
+
+        proposer_governance.active_proposals =
+            proposer_governance.active_proposals.saturating_sub(1);
+        proposer_governance.pledged_stake = proposer_governance
+            .pledged_stake
+            .saturating_sub(proposal.proposer_pledge);
 
         let total_votes_cast = proposal
             .yes_votes
@@ -460,89 +1260,100 @@ pub mod zero_sided_snipe {
             .checked_add(proposal.abstain_votes)
             .ok_or(ErrorCode::InvalidAmount)?;
 
-        let total_voting_power = staking_pool.total_staked_amount / 1_000_000;
+        let total_voting_power = staking_pool.total_voting_power();
+        let rules = governance_config.rules_for(proposal.proposal_type);
 
-        let quorum_required = total_voting_power
-            .checked_mul(QUORUM_PERCENTAGE)
-            .ok_or(ErrorCode::InvalidAmount)?
-            .checked_div(100)
-            .ok_or(ErrorCode::InvalidAmount)?;
+        // Optimistic proposals only settle once voting has ended
+        if proposal.is_optimistic {
+            require!(
+                clock.unix_timestamp >= proposal.voting_ends_at,
+                ErrorCode::VotingPeriodNotEnded
+            );
+        }
 
-        let quorum_met = total_votes_cast >= quorum_required;
+        let (outcome, quorum_met) = tally_outcome(
+            proposal,
+            governance_config,
+            total_voting_power,
+            clock.unix_timestamp,
+        )?;
 
-        if !quorum_met {
-            proposal.status = ProposalStatus::Failed;
-            proposal.finalized_at = clock.unix_timestamp;
+        // Before voting ends, only finalize once no remaining votes can change the outcome.
+        // Quorum must already be met, since later votes can only add to turnout. The
+        // snapshot keeps stake that voted and has since left the pool in the bound, and
+        // the live total covers stake added after voting opened.
+        if clock.unix_timestamp < proposal.voting_ends_at {
+            let remaining_votes = governance_config
+                .max_voting_power(proposal.voting_power_snapshot.max(total_voting_power))?
+                .saturating_sub(total_votes_cast);
+            require!(
+                quorum_met
+                    && is_outcome_decided(
+                        proposal,
+                        remaining_votes,
+                        &governance_config.proposal_settings,
+                        &rules,
+                    )?,
+                ErrorCode::VotingPeriodNotEnded
+            );
+        }
 
-            transfer_deposit_to_proposer(
-                &ctx.accounts.deposit_escrow_account,
-                &ctx.accounts.proposer_token_account,
-                &ctx.accounts.token_mint,
-                &ctx.accounts.program_authority,
-                &ctx.accounts.token_program,
-                proposal.deposit_amount,
-                ctx.accounts.staking_pool.authority_bump,
-            )?;
-
-            proposal.deposit_refunded = true;
-            return Ok(());
-        }
-
-        let yes_no_total = proposal
-            .yes_votes
-            .checked_add(proposal.no_votes)
-            .ok_or(ErrorCode::InvalidAmount)?;
-
-        if yes_no_total == 0 {
-            proposal.status = ProposalStatus::Failed;
-            proposal.finalized_at = clock.unix_timestamp;
-
-            transfer_deposit_to_proposer(
-                &ctx.accounts.deposit_escrow_account,
-                &ctx.accounts.proposer_token_account,
-                &ctx.accounts.token_mint,
-                &ctx.accounts.program_authority,
-                &ctx.accounts.token_program,
-                proposal.deposit_amount,
-                ctx.accounts.staking_pool.authority_bump,
-            )?;
+        proposal.finalized_at = clock.unix_timestamp;
 
-            proposal.deposit_refunded = true;
-            return Ok(());
-        }
+        match outcome {
+            ProposalStatus::Passed => {
+                // Emergency proposals are executable immediately
+                let timelock_duration = if proposal.proposal_type == ProposalType::Emergency {
+                    0
+                } else {
+                    rules.execution_delay_hours as i64 * SECONDS_PER_HOUR
+                };
+
+                proposal.status = ProposalStatus::Passed;
+                proposal.timelock_end = clock
+                    .unix_timestamp
+                    .checked_add(timelock_duration)
+                    .ok_or(ErrorCode::InvalidAmount)?;
+            }
+            ProposalStatus::Vetoed => {
+                proposal.status = ProposalStatus::Vetoed;
+                proposer_governance.proposals_slashed =
+                    proposer_governance.proposals_slashed.saturating_add(1);
 
-        let yes_percentage = proposal
-            .yes_votes
-            .checked_mul(100)
-            .ok_or(ErrorCode::InvalidAmount)?
-            .checked_div(yes_no_total)
-            .ok_or(ErrorCode::InvalidAmount)?;
-        let threshold_met = yes_percentage >= PASSING_THRESHOLD_PERCENTAGE;
+                slash_deposit(
+                    &ctx.accounts.deposit_escrow_account,
+                    ctx.accounts.treasury_account.as_ref(),
+                    &ctx.accounts.token_mint,
+                    &ctx.accounts.program_authority,
+                    &ctx.accounts.token_program,
+                    proposal.deposit_amount,
+                    governance_config.proposal_settings.burn_vetoed_deposits,
+                    ctx.accounts.staking_pool.authority_bump,
+                )?;
 
-        if !threshold_met {
-            proposal.status = ProposalStatus::Failed;
-            proposal.finalized_at = clock.unix_timestamp;
+                // The deposit is settled (slashed), so it can never be refunded
+                proposal.deposit_refunded = true;
+            }
+            _ => {
+                proposal.status = ProposalStatus::Failed;
+                proposer_governance.proposals_failed =
+                    proposer_governance.proposals_failed.saturating_add(1);
 
-            transfer_deposit_to_proposer(
-                &ctx.accounts.deposit_escrow_account,
-                &ctx.accounts.proposer_token_account,
-                &ctx.accounts.token_mint,
-                &ctx.accounts.program_authority,
-                &ctx.accounts.token_program,
-                proposal.deposit_amount,
-                ctx.accounts.staking_pool.authority_bump,
-            )?;
+                transfer_deposit_to_proposer(
+                    &ctx.accounts.deposit_escrow_account,
+                    &ctx.accounts.proposer_token_account,
+                    &ctx.accounts.token_mint,
+                    &ctx.accounts.program_authority,
+                    &ctx.accounts.token_program,
+                    proposal.deposit_amount,
+                    ctx.accounts.staking_pool.authority_bump,
+                )?;
 
-            proposal.deposit_refunded = true;
-            return Ok(());
+                proposal.deposit_refunded = true;
+            }
         }
 
-        proposal.status = ProposalStatus::Passed;
-        proposal.finalized_at = clock.unix_timestamp;
-        proposal.timelock_end = clock
-            .unix_timestamp
-            .checked_add(TIME_LOCK_DURATION)
-            .ok_or(ErrorCode::InvalidAmount)?;
+        emit!(proposal.finalized_event(quorum_met));
 
         Ok(())
     }
@@ -551,22 +1362,20 @@ pub mod zero_sided_snipe {
         let proposal = &mut ctx.accounts.proposal_account;
         let clock = Clock::get()?;
 
-        require!(
-            proposal.status == ProposalStatus::Passed,
-            ErrorCode::ProposalNotPassed
-        );
-        require!(
-            clock.unix_timestamp >= proposal.timelock_end,
-            ErrorCode::TimelockNotExpired
-        );
-        require!(
-            proposal.executed_at == 0,
-            ErrorCode::ProposalAlreadyExecuted
-        );
+        require_proposal_executable(
+            proposal,
+            ctx.accounts
+                .governance_config
+                .execution_settings
+                .execution_grace_period_hours,
+            clock.unix_timestamp,
+        )?;
 
-        let execution_result = match proposal.proposal_type {
-            ProposalType::Text => Ok(()),
-            ProposalType::TreasuryTransfer => execute_treasury_transfer(
+        let payload = ExecutionPayload::decode(&proposal.execution_data)?;
+
+        let execution_result = match payload {
+            ExecutionPayload::Text { .. } => Ok(()),
+            ExecutionPayload::TreasuryTransfer { recipient, amount } => execute_treasury_transfer(
                 ctx.accounts
                     .treasury_account
                     .as_ref()
@@ -584,13 +1393,24 @@ pub mod zero_sided_snipe {
                     .token_program
                     .as_ref()
                     .ok_or(ErrorCode::MissingTokenProgram)?,
-                &proposal.execution_data,
+                recipient,
+                amount,
                 ctx.accounts.staking_pool.authority_bump,
             ),
-            ProposalType::ParameterUpdate => execute_parameter_update(
-                &mut ctx.accounts.governance_config, // No .as_mut().ok_or() needed
-                &proposal.execution_data,
-            ),
+            ExecutionPayload::ParameterUpdate { update } => apply_parameter_update(
+                &mut ctx.accounts.governance_config,
+                update,
+                proposal.key(),
+            )
+            .and_then(|_| ctx.accounts.governance_config.validate_invariants()),
+            ExecutionPayload::CustomInstructions | ExecutionPayload::Emergency => {
+                verify_proposal_instructions_executed(
+                    ctx.accounts
+                        .proposal_instructions
+                        .as_ref()
+                        .ok_or(ErrorCode::MissingProposalInstructions)?,
+                )
+            }
         };
 
         match execution_result {
@@ -631,297 +1451,2881 @@ pub mod zero_sided_snipe {
 
         Ok(())
     }
-}
 
-fn execute_treasury_transfer<'info>(
-    treasury_account: &InterfaceAccount<'info, TokenAccount>,
-    recipient_account: &InterfaceAccount<'info, TokenAccount>,
-    token_mint: &InterfaceAccount<'info, Mint>,
-    program_authority: &UncheckedAccount<'info>,
-    token_program: &Interface<'info, TokenInterface>,
-    execution_data: &[u8],
-    authority_bump: u8,
-) -> Result<()> {
-    require!(execution_data.len() >= 40, ErrorCode::InvalidExecutionData);
+    pub fn execute_proposal_instruction<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ExecuteProposalInstruction<'info>>,
+        instruction_index: u16,
+    ) -> Result<()> {
+        let proposal = &ctx.accounts.proposal_account;
+        let proposal_instructions = &mut ctx.accounts.proposal_instructions;
+        let clock = Clock::get()?;
 
-    let amount_bytes: [u8; 8] = execution_data[32..40]
-        .try_into()
-        .map_err(|_| ErrorCode::InvalidExecutionData)?;
-    let amount = u64::from_le_bytes(amount_bytes);
+        require!(
+            matches!(
+                proposal.proposal_type,
+                ProposalType::CustomInstructions | ProposalType::Emergency
+            ),
+            ErrorCode::InvalidProposalType
+        );
+        require_proposal_executable(
+            proposal,
+            ctx.accounts
+                .governance_config
+                .execution_settings
+                .execution_grace_period_hours,
+            clock.unix_timestamp,
+        )?;
 
-    require!(
-        treasury_account.amount >= amount,
-        ErrorCode::InsufficientTreasuryBalance
-    );
+        // Instructions run strictly in the order they were voted on
+        require!(
+            instruction_index == proposal_instructions.executed_count,
+            ErrorCode::InstructionExecutedOutOfOrder
+        );
 
-    let authority_bump_arr = &[authority_bump];
-    let authority_seeds = &[PROGRAM_AUTHORITY_SEED.as_ref(), authority_bump_arr.as_ref()];
-    let signer_seeds = &[&authority_seeds[..]];
+        let index = instruction_index as usize;
+        let stored = proposal_instructions
+            .instructions
+            .get(index)
+            .ok_or(ErrorCode::InvalidInstructionIndex)?;
+
+        let instruction = Instruction {
+            program_id: stored.program_id,
+            accounts: stored
+                .accounts
+                .iter()
+                .map(|meta| AccountMeta {
+                    pubkey: meta.pubkey,
+                    is_signer: meta.is_signer,
+                    is_writable: meta.is_writable,
+                })
+                .collect(),
+            data: stored.data.clone(),
+        };
 
-    transfer_checked(
-        CpiContext::new_with_signer(
-            token_program.to_account_info(),
-            TransferChecked {
-                from: treasury_account.to_account_info(),
-                mint: token_mint.to_account_info(),
-                to: recipient_account.to_account_info(),
-                authority: program_authority.to_account_info(),
-            },
-            signer_seeds,
-        ),
-        amount,
-        token_mint.decimals,
-    )?;
+        let authority_bump = &[ctx.accounts.staking_pool.authority_bump];
+        let authority_seeds = &[PROGRAM_AUTHORITY_SEED, authority_bump.as_ref()];
+        let signer_seeds = &[&authority_seeds[..]];
 
-    Ok(())
-}
+        let mut account_infos = ctx.remaining_accounts.to_vec();
+        account_infos.push(ctx.accounts.program_authority.to_account_info());
 
-fn execute_parameter_update<'info>(
-    governance_config: &mut Account<'info, GovernanceConfig>,
-    execution_data: &[u8],
-) -> Result<()> {
-    require!(execution_data.len() >= 9, ErrorCode::InvalidExecutionData);
+        invoke_signed(&instruction, &account_infos, signer_seeds)?;
 
-    let parameter_id = execution_data[0];
-    let value_bytes: [u8; 8] = execution_data[1..9]
-        .try_into()
-        .map_err(|_| ErrorCode::InvalidExecutionData)?;
-    let new_value = u64::from_le_bytes(value_bytes);
+        proposal_instructions.instructions[index].executed_at = clock.unix_timestamp;
+        proposal_instructions.executed_count = proposal_instructions
+            .executed_count
+            .checked_add(1)
+            .ok_or(ErrorCode::InvalidAmount)?;
 
-    let clock = Clock::get()?;
+        msg!(
+            "✅ Executed instruction {}/{} for proposal #{}",
+            proposal_instructions.executed_count,
+            proposal_instructions.instructions.len(),
+            proposal.proposal_id
+        );
+
+        Ok(())
+    }
+
+    /// Permissionless: closes out a passed proposal nobody executed within the
+    /// execution grace period. The proposal passed, so the deposit goes back to
+    /// the proposer.
+    pub fn expire_proposal(ctx: Context<ExpireProposal>) -> Result<()> {
+        let proposal = &mut ctx.accounts.proposal_account;
+        let governance_config = &ctx.accounts.governance_config;
+        let clock = Clock::get()?;
+
+        require!(
+            proposal.status == ProposalStatus::Passed,
+            ErrorCode::ProposalNotPassed
+        );
+        require!(
+            proposal.executed_at == 0,
+            ErrorCode::ProposalAlreadyExecuted
+        );
+
+        let deadline = execution_deadline(
+            proposal,
+            governance_config
+                .execution_settings
+                .execution_grace_period_hours,
+        )?;
+        require!(
+            clock.unix_timestamp > deadline,
+            ErrorCode::ExecutionGracePeriodActive
+        );
+
+        proposal.status = ProposalStatus::Expired;
+
+        if !proposal.deposit_refunded {
+            transfer_deposit_to_proposer(
+                &ctx.accounts.deposit_escrow_account,
+                &ctx.accounts.proposer_token_account,
+                &ctx.accounts.token_mint,
+                &ctx.accounts.program_authority,
+                &ctx.accounts.token_program,
+                proposal.deposit_amount,
+                ctx.accounts.staking_pool.authority_bump,
+            )?;
+
+            proposal.deposit_refunded = true;
+        }
+
+        Ok(())
+    }
+
+    /// Permissionless: closes a settled proposal once its archival window and the
+    /// voters' lock period have passed, returning its rent and that of its stored
+    /// instructions to the proposer. Passing `proposal_summary` keeps a compact
+    /// record of the outcome (paid by the caller), and vote records can be closed
+    /// in the same call by passing `(vote_record, voter)` pairs as remaining accounts.
+    pub fn close_proposal<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CloseProposal<'info>>,
+    ) -> Result<()> {
+        let proposal = &ctx.accounts.proposal_account;
+        let governance_config = &ctx.accounts.governance_config;
+        let clock = Clock::get()?;
+
+        require!(proposal.is_settled(), ErrorCode::ProposalNotSettled);
+        require!(
+            proposal.sponsor_count == 0,
+            ErrorCode::SponsorshipsNotSettled
+        );
+
+        let archival_period = governance_config
+            .execution_settings
+            .proposal_archival_period_hours as i64
+            * SECONDS_PER_HOUR;
+        let archived_at = proposal
+            .finalized_at
+            .max(proposal.executed_at)
+            .checked_add(archival_period)
+            .ok_or(ErrorCode::InvalidAmount)?;
+        let lock_end = proposal
+            .tally_ends_at()
+            .checked_add(VOTE_LOCK_PERIOD)
+            .ok_or(ErrorCode::InvalidAmount)?;
+        // Unrevealed votes must stay penalizable, however short the archival period
+        let penalty_ends_at = if proposal.is_private {
+            governance_config.unrevealed_penalty_ends_at(proposal)?
+        } else {
+            0
+        };
+        require_gte!(
+            clock.unix_timestamp,
+            archived_at.max(lock_end).max(penalty_ends_at),
+            ErrorCode::ArchivalPeriodActive
+        );
 
-    match parameter_id {
-        0 => {
+        // The stored instructions go with the proposal so they can't outlive it
+        if matches!(
+            proposal.proposal_type,
+            ProposalType::CustomInstructions | ProposalType::Emergency
+        ) {
             require!(
-                new_value >= 1 && new_value <= 100,
-                ErrorCode::InvalidParameterValue
-            );
-            msg!(
-                "Updating quorum: {} -> {}",
-                governance_config.quorum_percentage,
-                new_value
+                ctx.accounts.proposal_instructions.is_some(),
+                ErrorCode::MissingProposalInstructions
             );
-            governance_config.quorum_percentage = new_value;
         }
-        1 => {
+
+        if let Some(summary) = ctx.accounts.proposal_summary.as_mut() {
+            summary.proposal_id = proposal.proposal_id;
+            summary.proposer = proposal.proposer;
+            summary.proposal_type = proposal.proposal_type;
+            summary.status = proposal.status;
+            summary.yes_votes = proposal.yes_votes;
+            summary.no_votes = proposal.no_votes;
+            summary.abstain_votes = proposal.abstain_votes;
+            summary.veto_votes = proposal.veto_votes;
+            summary.total_voters = proposal.total_voters;
+            summary.created_at = proposal.created_at;
+            summary.finalized_at = proposal.finalized_at;
+            summary.executed_at = proposal.executed_at;
+            summary.content_hash = proposal.content_hash;
+            summary.closed_at = clock.unix_timestamp;
+            summary.bump = ctx.bumps.proposal_summary;
+        }
+
+        close_vote_records(
+            proposal,
+            governance_config,
+            clock.unix_timestamp,
+            ctx.remaining_accounts,
+        )?;
+
+        Ok(())
+    }
+
+    /// Returns a vote record's rent to the voter once the proposal is finalized and
+    /// the vote lock has passed. Also works after the proposal itself was closed.
+    pub fn close_vote_record(ctx: Context<CloseVoteRecord>) -> Result<()> {
+        let proposal_info = &ctx.accounts.proposal_account;
+        let clock = Clock::get()?;
+
+        // An empty proposal PDA means close_proposal already ran, which requires
+        // the proposal to be settled and the vote lock to have passed
+        if !proposal_info.data_is_empty() {
+            let data = proposal_info.try_borrow_data()?;
+            let proposal = ProposalAccount::try_deserialize(&mut &data[..])?;
+
             require!(
-                new_value >= 51 && new_value <= 100,
-                ErrorCode::InvalidParameterValue
-            );
-            msg!(
-                "Updating threshold: {} -> {}",
-                governance_config.passing_threshold,
-                new_value
+                proposal.finalized_at != 0,
+                ErrorCode::ProposalNotFinalized
             );
-            governance_config.passing_threshold = new_value;
-        }
-        2 => {
-            require!(new_value <= 30 * 86400, ErrorCode::InvalidParameterValue);
-            msg!(
-                "Updating timelock: {} -> {} seconds",
-                governance_config.timelock_duration,
-                new_value
+
+            let lock_end = proposal
+                .tally_ends_at()
+                .checked_add(VOTE_LOCK_PERIOD)
+                .ok_or(ErrorCode::InvalidAmount)?;
+            require_gte!(
+                clock.unix_timestamp,
+                lock_end,
+                ErrorCode::TokensLockedForGovernance
             );
-            governance_config.timelock_duration = new_value as i64;
-        }
-        _ => {
-            return Err(ErrorCode::InvalidParameterId.into());
+
+            // Unrevealed commitments stay open through the penalty window so the voter
+            // can't close them to dodge penalize_unrevealed_vote
+            if proposal.is_private && ctx.accounts.vote_record.vote_choice.is_none() {
+                let penalty_ends_at = ctx
+                    .accounts
+                    .governance_config
+                    .unrevealed_penalty_ends_at(&proposal)?;
+                require_gte!(
+                    clock.unix_timestamp,
+                    penalty_ends_at,
+                    ErrorCode::TokensLockedForGovernance
+                );
+            }
         }
+
+        Ok(())
     }
 
-    governance_config.last_updated = clock.unix_timestamp;
+    /// Edits a draft's content. The proposal type is fixed at creation, so the new
+    /// execution data must decode to the same type; the account is resized to fit.
+    pub fn update_draft_proposal(
+        ctx: Context<UpdateDraftProposal>,
+        title: String,
+        content: ProposalContent,
+        execution_data: Vec<u8>,
+    ) -> Result<()> {
+        let proposal = &mut ctx.accounts.proposal_account;
+        let governance_config = &ctx.accounts.governance_config;
 
-    Ok(())
-}
+        require!(
+            proposal.status == ProposalStatus::Draft,
+            ErrorCode::ProposalNotDraft
+        );
 
-fn calculate_hybrid_voting_power(stake_amount: u64, stake_duration_days: u32) -> u64 {
-    let tokens = stake_amount / 1_000_000;
+        let (description, metadata_uri, content_hash) = validate_proposal_content(
+            governance_config,
+            &title,
+            content,
+            proposal.proposal_type,
+            &execution_data,
+            proposal.is_optimistic,
+        )?;
 
-    let base_power = if tokens <= 100_000 {
-        tokens
-    } else {
-        100_000 + ((tokens - 100_000) as f64).sqrt() as u64
-    };
+        proposal.title = title;
+        proposal.description = description;
+        proposal.execution_data = execution_data;
+        proposal.metadata_uri = metadata_uri;
+        proposal.content_hash = content_hash;
 
-    let time_multiplier = match stake_duration_days {
-        0..=30 => 100,
-        31..=90 => 120,
-        91..=365 => 150,
-        _ => 200,
-    };
+        Ok(())
+    }
 
-    (base_power * time_multiplier) / 100
-}
+    /// Moves a draft into voting. Content can no longer be edited from here on,
+    /// so the content hash voters see is final.
+    pub fn activate_proposal(ctx: Context<ActivateProposal>) -> Result<()> {
+        let proposal = &mut ctx.accounts.proposal_account;
+        let governance_config = &ctx.accounts.governance_config;
+        let clock = Clock::get()?;
 
-fn transfer_deposit_to_proposer<'info>(
-    deposit_escrow: &InterfaceAccount<'info, TokenAccount>,
-    proposer_token_account: &InterfaceAccount<'info, TokenAccount>,
-    token_mint: &InterfaceAccount<'info, Mint>,
-    program_authority: &UncheckedAccount<'info>,
-    token_program: &Interface<'info, TokenInterface>,
-    amount: u64,
-    authority_bump: u8,
-) -> Result<()> {
-    let authority_bump_arr = &[authority_bump];
-    let authority_seeds = &[PROGRAM_AUTHORITY_SEED.as_ref(), authority_bump_arr.as_ref()];
-    let signer_seeds = &[&authority_seeds[..]];
+        require!(
+            proposal.status == ProposalStatus::Draft,
+            ErrorCode::ProposalNotDraft
+        );
+        require_gte!(
+            proposal.backing_stake,
+            proposal.required_stake,
+            ErrorCode::InsufficientStakeToPropose
+        );
 
-    transfer_checked(
-        CpiContext::new_with_signer(
-            token_program.to_account_info(),
-            TransferChecked {
-                from: deposit_escrow.to_account_info(),
-                mint: token_mint.to_account_info(),
-                to: proposer_token_account.to_account_info(),
-                authority: program_authority.to_account_info(),
-            },
-            signer_seeds,
-        ),
-        amount,
-        token_mint.decimals,
-    )?;
+        if proposal.proposal_type == ProposalType::Emergency {
+            require!(
+                governance_config.is_emergency_active(clock.unix_timestamp),
+                ErrorCode::EmergencyModeNotActive
+            );
+            proposal.voting_period_hours =
+                governance_config.emergency_settings.emergency_voting_period_hours;
+        }
 
-    Ok(())
-}
+        proposal.voting_ends_at = clock
+            .unix_timestamp
+            .checked_add(proposal.voting_period_hours as i64 * SECONDS_PER_HOUR)
+            .ok_or(ErrorCode::InvalidAmount)?;
+        if proposal.is_private {
+            proposal.reveal_ends_at = governance_config.reveal_ends_at(proposal.voting_ends_at)?;
+        }
+        proposal.voting_power_snapshot = ctx.accounts.staking_pool.total_voting_power();
+        proposal.status = ProposalStatus::Active;
 
-fn get_voting_duration_seconds(period_index: u8) -> Result<i64> {
-    match period_index {
-        0 => Ok(300),  // 5 minutes for testing
-        1 => Ok(900),  // 15 minutes for testing
-        2 => Ok(1500), // 25 minutes for testing
-        _ => Err(ErrorCode::InvalidVotingPeriod.into()),
+        Ok(())
     }
-}
 
-#[account]
-pub struct StakingPool {
+    /// Pledges part of the sponsor's stake to a draft proposal and pays the matching
+    /// share of the deposit, until the combined stake meets the proposal requirement.
+    pub fn sponsor_proposal(ctx: Context<SponsorProposal>, stake_amount: u64) -> Result<()> {
+        let proposal = &mut ctx.accounts.proposal_account;
+        let sponsor_staking = &ctx.accounts.sponsor_staking_account;
+        let sponsor_governance = &mut ctx.accounts.sponsor_governance_account;
+        let governance_config = &ctx.accounts.governance_config;
+        let sponsorship = &mut ctx.accounts.sponsorship;
+        let clock = Clock::get()?;
+
+        require!(
+            proposal.status == ProposalStatus::Draft,
+            ErrorCode::ProposalNotDraft
+        );
+        require!(
+            proposal.proposer != ctx.accounts.sponsor.key(),
+            ErrorCode::InvalidSponsor
+        );
+        require_gt!(
+            MAX_PROPOSAL_SPONSORS,
+            proposal.sponsor_count,
+            ErrorCode::TooManySponsors
+        );
+        require!(stake_amount > 0, ErrorCode::InvalidAmount);
+
+        let remaining_requirement = proposal
+            .required_stake
+            .saturating_sub(proposal.backing_stake);
+        require_gte!(
+            remaining_requirement,
+            stake_amount,
+            ErrorCode::SponsorshipExceedsRequirement
+        );
+
+        let available_stake = sponsor_staking
+            .staked_amount
+            .saturating_sub(sponsor_governance.pledged_stake);
+        require_gte!(
+            available_stake,
+            stake_amount,
+            ErrorCode::InsufficientStakeToPropose
+        );
+
+        let stake_duration = clock.unix_timestamp - sponsor_staking.timestamp;
+        let min_stake_duration =
+            governance_config.parameter_bounds.min_stake_duration_days as i64 * 86400;
+        require_gte!(
+            stake_duration,
+            min_stake_duration,
+            ErrorCode::InsufficientStakeDurationToPropose
+        );
+
+        let deposit_amount =
+            governance_config.calculate_proposal_deposit(proposal.proposal_type, stake_amount)?;
+
+        transfer_checked(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.sponsor_token_account.to_account_info(),
+                    mint: ctx.accounts.deposit_token_mint.to_account_info(),
+                    to: ctx.accounts.deposit_escrow_account.to_account_info(),
+                    authority: ctx.accounts.sponsor.to_account_info(),
+                },
+            ),
+            deposit_amount,
+            ctx.accounts.deposit_token_mint.decimals,
+        )?;
+
+        sponsorship.proposal_id = proposal.proposal_id;
+        sponsorship.sponsor = ctx.accounts.sponsor.key();
+        sponsorship.stake_amount = stake_amount;
+        sponsorship.deposit_amount = deposit_amount;
+        sponsorship.bump = ctx.bumps.sponsorship;
+
+        proposal.backing_stake = proposal
+            .backing_stake
+            .checked_add(stake_amount)
+            .ok_or(ErrorCode::InvalidAmount)?;
+        proposal.sponsor_count = proposal
+            .sponsor_count
+            .checked_add(1)
+            .ok_or(ErrorCode::InvalidAmount)?;
+
+        sponsor_governance.pledged_stake = sponsor_governance
+            .pledged_stake
+            .checked_add(stake_amount)
+            .ok_or(ErrorCode::InvalidAmount)?;
+
+        Ok(())
+    }
+
+    /// Releases a sponsorship. While the proposal is still a draft only the sponsor
+    /// can withdraw; once the proposal is settled anyone can settle it, and the
+    /// sponsor's deposit follows the proposer's: slashed if vetoed, refunded otherwise.
+    pub fn settle_sponsorship(ctx: Context<SettleSponsorship>) -> Result<()> {
+        let proposal = &mut ctx.accounts.proposal_account;
+        let sponsorship = &ctx.accounts.sponsorship;
+        let sponsor_governance = &mut ctx.accounts.sponsor_governance_account;
+        let governance_config = &ctx.accounts.governance_config;
+
+        if proposal.status == ProposalStatus::Draft {
+            require!(
+                ctx.accounts.caller.key() == sponsorship.sponsor,
+                ErrorCode::InvalidSponsor
+            );
+            proposal.backing_stake = proposal
+                .backing_stake
+                .saturating_sub(sponsorship.stake_amount);
+        } else {
+            require!(proposal.is_settled(), ErrorCode::ProposalNotSettled);
+        }
+
+        if proposal.status == ProposalStatus::Vetoed {
+            slash_deposit(
+                &ctx.accounts.deposit_escrow_account,
+                ctx.accounts.treasury_account.as_ref(),
+                &ctx.accounts.token_mint,
+                &ctx.accounts.program_authority,
+                &ctx.accounts.token_program,
+                sponsorship.deposit_amount,
+                governance_config.proposal_settings.burn_vetoed_deposits,
+                ctx.accounts.staking_pool.authority_bump,
+            )?;
+        } else {
+            transfer_deposit_to_proposer(
+                &ctx.accounts.deposit_escrow_account,
+                &ctx.accounts.sponsor_token_account,
+                &ctx.accounts.token_mint,
+                &ctx.accounts.program_authority,
+                &ctx.accounts.token_program,
+                sponsorship.deposit_amount,
+                ctx.accounts.staking_pool.authority_bump,
+            )?;
+        }
+
+        proposal.sponsor_count = proposal.sponsor_count.saturating_sub(1);
+        sponsor_governance.pledged_stake = sponsor_governance
+            .pledged_stake
+            .saturating_sub(sponsorship.stake_amount);
+
+        Ok(())
+    }
+
+    /// Pushes a voter's stake lock out to match a voting period that was extended
+    /// after they voted. Permissionless, for voters the extending vote didn't cover.
+    pub fn refresh_vote_lock(ctx: Context<RefreshVoteLock>) -> Result<()> {
+        extend_stake_lock(
+            &mut ctx.accounts.governance_account,
+            &ctx.accounts.proposal_account,
+        )
+    }
+
+    /// Commits to a private vote with `vote_commitment(...)`, binding the voter's
+    /// current voting power. Tallies only change when the vote is revealed.
+    pub fn commit_vote(ctx: Context<CommitVote>, commitment: [u8; 32]) -> Result<()> {
+        let proposal = &mut ctx.accounts.proposal_account;
+        let governance_account = &mut ctx.accounts.governance_account;
+        let vote_record = &mut ctx.accounts.vote_record;
+        let clock = Clock::get()?;
+
+        require!(proposal.is_private, ErrorCode::ProposalNotPrivate);
+        validate_vote_eligibility(
+            proposal,
+            &ctx.accounts.user_staking_account,
+            governance_account,
+            clock.unix_timestamp,
+        )?;
+
+        proposal.last_vote_at = clock.unix_timestamp;
+
+        // Locked through the reveal phase, so the committed power stays backed by stake.
+        // Participation only counts once the vote is revealed.
+        extend_stake_lock(governance_account, proposal)?;
+        governance_account.last_vote_timestamp = clock.unix_timestamp;
+
+        vote_record.voter = ctx.accounts.voter.key();
+        vote_record.proposal_id = proposal.proposal_id;
+        vote_record.vote_choice = None;
+        vote_record.voting_power = governance_account.voting_power_cache;
+        vote_record.voted_at = clock.unix_timestamp;
+        vote_record.bump = ctx.bumps.vote_record;
+        vote_record.commitment = commitment;
+
+        emit!(VoteCommitted {
+            proposal_id: proposal.proposal_id,
+            voter: vote_record.voter,
+            voting_power: vote_record.voting_power,
+            timestamp: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Opens a committed vote after voting ends and adds it to the tally.
+    pub fn reveal_vote(
+        ctx: Context<RevealVote>,
+        vote_choice: VoteChoice,
+        salt: [u8; 32],
+    ) -> Result<()> {
+        let proposal = &mut ctx.accounts.proposal_account;
+        let vote_record = &mut ctx.accounts.vote_record;
+        let governance_account = &mut ctx.accounts.governance_account;
+        let clock = Clock::get()?;
+
+        require!(proposal.is_private, ErrorCode::ProposalNotPrivate);
+        require!(
+            proposal.status == ProposalStatus::Active,
+            ErrorCode::ProposalNotActive
+        );
+        require!(
+            clock.unix_timestamp >= proposal.voting_ends_at,
+            ErrorCode::VotingPeriodNotEnded
+        );
+        require!(
+            clock.unix_timestamp < proposal.reveal_ends_at,
+            ErrorCode::RevealPeriodEnded
+        );
+        require!(
+            vote_record.vote_choice.is_none(),
+            ErrorCode::VoteAlreadyRevealed
+        );
+        require!(
+            vote_commitment(
+                proposal.proposal_id,
+                &vote_record.voter,
+                vote_record.voting_power,
+                vote_choice,
+                &salt,
+            ) == vote_record.commitment,
+            ErrorCode::InvalidVoteReveal
+        );
+
+        record_vote(proposal, vote_choice, vote_record.voting_power)?;
+
+        vote_record.vote_choice = Some(vote_choice);
+        governance_account.participation_count = governance_account
+            .participation_count
+            .checked_add(1)
+            .ok_or(ErrorCode::InvalidAmount)?;
+
+        Ok(())
+    }
+
+    /// Extends the stake lock of a voter who never revealed a private vote by the
+    /// configured penalty. Permissionless once the reveal phase is over.
+    pub fn penalize_unrevealed_vote(ctx: Context<PenalizeUnrevealedVote>) -> Result<()> {
+        let proposal = &ctx.accounts.proposal_account;
+        let governance_account = &mut ctx.accounts.governance_account;
+        let governance_config = &ctx.accounts.governance_config;
+        let clock = Clock::get()?;
+
+        require!(proposal.is_private, ErrorCode::ProposalNotPrivate);
+        require!(
+            clock.unix_timestamp >= proposal.reveal_ends_at,
+            ErrorCode::RevealPeriodActive
+        );
+        require!(
+            ctx.accounts.vote_record.vote_choice.is_none(),
+            ErrorCode::VoteAlreadyRevealed
+        );
+        require!(
+            governance_config.proposal_settings.unrevealed_vote_penalty_hours > 0,
+            ErrorCode::UnrevealedVotePenaltyDisabled
+        );
+
+        let penalty_ends_at = governance_config.unrevealed_penalty_ends_at(proposal)?;
+        governance_account.stake_lock_end = governance_account.stake_lock_end.max(penalty_ends_at);
+
+        Ok(())
+    }
+
+    /// Withdraws a draft before voting opens, refunding the proposer's deposit.
+    /// The cancelled draft counts as settled, so sponsors recover their pledges and
+    /// deposits through `settle_sponsorship`.
+    pub fn cancel_draft(ctx: Context<CancelDraft>) -> Result<()> {
+        let proposal = &mut ctx.accounts.proposal_account;
+        let proposer_governance = &mut ctx.accounts.proposer_governance_account;
+        let clock = Clock::get()?;
+
+        require!(
+            proposal.status == ProposalStatus::Draft,
+            ErrorCode::ProposalNotDraft
+        );
+
+        transfer_deposit_to_proposer(
+            &ctx.accounts.deposit_escrow_account,
+            &ctx.accounts.proposer_token_account,
+            &ctx.accounts.token_mint,
+            &ctx.accounts.program_authority,
+            &ctx.accounts.token_program,
+            proposal.deposit_amount,
+            ctx.accounts.staking_pool.authority_bump,
+        )?;
+
+        proposal.status = ProposalStatus::Cancelled;
+        proposal.finalized_at = clock.unix_timestamp;
+        proposal.deposit_refunded = true;
+        proposer_governance.active_proposals =
+            proposer_governance.active_proposals.saturating_sub(1);
+        proposer_governance.pledged_stake = proposer_governance
+            .pledged_stake
+            .saturating_sub(proposal.proposer_pledge);
+
+        Ok(())
+    }
+
+    /// Grows a governance account created before the proposal tracking and pledge
+    /// fields were added to the current size. The new fields start zeroed: no
+    /// proposal history, no active proposals and nothing pledged.
+    pub fn migrate_governance_account(ctx: Context<MigrateGovernanceAccount>) -> Result<()> {
+        let governance_info = ctx.accounts.governance_account.to_account_info();
+
+        require_gt!(
+            GovernanceAccount::SPACE,
+            governance_info.data_len(),
+            ErrorCode::GovernanceAccountUpToDate
+        );
+        // Rejects anything that isn't a governance account before touching it
+        GovernanceAccount::try_deserialize_legacy(&governance_info.try_borrow_data()?)?;
+
+        let rent_due = Rent::get()?
+            .minimum_balance(GovernanceAccount::SPACE)
+            .saturating_sub(governance_info.lamports());
+        if rent_due > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.staker.to_account_info(),
+                        to: governance_info.clone(),
+                    },
+                ),
+                rent_due,
+            )?;
+        }
+
+        governance_info.realloc(GovernanceAccount::SPACE, true)?;
+
+        Ok(())
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn execute_treasury_transfer<'info>(
+    treasury_account: &InterfaceAccount<'info, TokenAccount>,
+    recipient_account: &InterfaceAccount<'info, TokenAccount>,
+    token_mint: &InterfaceAccount<'info, Mint>,
+    program_authority: &UncheckedAccount<'info>,
+    token_program: &Interface<'info, TokenInterface>,
+    recipient: Pubkey,
+    amount: u64,
+    authority_bump: u8,
+) -> Result<()> {
+    require!(
+        recipient_account.owner == recipient,
+        ErrorCode::InvalidRecipientAccount
+    );
+    require!(
+        treasury_account.amount >= amount,
+        ErrorCode::InsufficientTreasuryBalance
+    );
+
+    let authority_bump_arr = &[authority_bump];
+    let authority_seeds = &[PROGRAM_AUTHORITY_SEED, authority_bump_arr.as_ref()];
+    let signer_seeds = &[&authority_seeds[..]];
+
+    transfer_checked(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            TransferChecked {
+                from: treasury_account.to_account_info(),
+                mint: token_mint.to_account_info(),
+                to: recipient_account.to_account_info(),
+                authority: program_authority.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+        token_mint.decimals,
+    )?;
+
+    Ok(())
+}
+
+fn require_proposal_executable(
+    proposal: &ProposalAccount,
+    grace_period_hours: u32,
+    now: i64,
+) -> Result<()> {
+    require!(
+        proposal.status == ProposalStatus::Passed,
+        ErrorCode::ProposalNotPassed
+    );
+    require_gte!(
+        now,
+        proposal.timelock_end,
+        ErrorCode::TimelockNotExpired
+    );
+    require!(
+        now <= execution_deadline(proposal, grace_period_hours)?,
+        ErrorCode::ProposalExecutionExpired
+    );
+    require!(
+        proposal.executed_at == 0,
+        ErrorCode::ProposalAlreadyExecuted
+    );
+
+    Ok(())
+}
+
+fn execution_deadline(proposal: &ProposalAccount, grace_period_hours: u32) -> Result<i64> {
+    let deadline = proposal
+        .timelock_end
+        .checked_add(grace_period_hours as i64 * SECONDS_PER_HOUR)
+        .ok_or(ErrorCode::InvalidAmount)?;
+
+    Ok(deadline)
+}
+
+fn apply_parameter_update(
+    governance_config: &mut GovernanceConfig,
+    update_type: ParameterUpdateType,
+    updated_by: Pubkey,
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    governance_config.validate_parameter_bounds(&update_type)?;
+
+    match update_type {
+        ParameterUpdateType::TimeMultiplier { tier, multiplier } => {
+            let old_value = match tier {
+                1 => governance_config.time_multipliers.tier_1_multiplier,
+                2 => governance_config.time_multipliers.tier_2_multiplier,
+                3 => governance_config.time_multipliers.tier_3_multiplier,
+                _ => return Err(ErrorCode::InvalidParameterValue.into()),
+            };
+
+            match tier {
+                1 => governance_config.time_multipliers.tier_1_multiplier = multiplier,
+                2 => governance_config.time_multipliers.tier_2_multiplier = multiplier,
+                3 => governance_config.time_multipliers.tier_3_multiplier = multiplier,
+                _ => return Err(ErrorCode::InvalidParameterValue.into()),
+            }
+
+            emit!(ParameterUpdated {
+                parameter_type: "time_multiplier".to_string(),
+                old_value: old_value as u64,
+                new_value: multiplier as u64,
+                updated_by,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+        ParameterUpdateType::ProposalStake { amount } => {
+            let old_stake = governance_config.proposal_settings.base_proposal_stake;
+            governance_config.proposal_settings.base_proposal_stake = amount;
+
+            emit!(ParameterUpdated {
+                parameter_type: "proposal_stake".to_string(),
+                old_value: old_stake,
+                new_value: amount,
+                updated_by,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+        ParameterUpdateType::QuorumThreshold { percentage } => {
+            let old_quorum = governance_config
+                .proposal_settings
+                .default_quorum_percentage;
+            governance_config
+                .proposal_settings
+                .default_quorum_percentage = percentage;
+
+            emit!(ParameterUpdated {
+                parameter_type: "quorum_threshold".to_string(),
+                old_value: old_quorum as u64,
+                new_value: percentage as u64,
+                updated_by,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+        ParameterUpdateType::VotingPeriod { hours } => {
+            let old_period = governance_config
+                .proposal_settings
+                .default_voting_period_hours;
+            governance_config
+                .proposal_settings
+                .default_voting_period_hours = hours;
+
+            emit!(ParameterUpdated {
+                parameter_type: "voting_period".to_string(),
+                old_value: old_period as u64,
+                new_value: hours as u64,
+                updated_by,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+        ParameterUpdateType::EmergencySettings {
+            quorum_reduction,
+            voting_period,
+        } => {
+            governance_config
+                .emergency_settings
+                .emergency_quorum_reduction = quorum_reduction;
+            governance_config
+                .emergency_settings
+                .emergency_voting_period_hours = voting_period;
+
+            emit!(EmergencySettingsUpdated {
+                quorum_reduction,
+                voting_period,
+                updated_by,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+        ParameterUpdateType::TreasuryLimit { percentage } => {
+            let old_limit = governance_config
+                .parameter_bounds
+                .max_treasury_withdrawal_percentage;
+            governance_config
+                .parameter_bounds
+                .max_treasury_withdrawal_percentage = percentage;
+
+            emit!(ParameterUpdated {
+                parameter_type: "treasury_limit".to_string(),
+                old_value: old_limit as u64,
+                new_value: percentage as u64,
+                updated_by,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+        ParameterUpdateType::ExecutionDelay { hours } => {
+            let old_delay = governance_config.execution_settings.execution_delay_hours;
+            governance_config.execution_settings.execution_delay_hours = hours;
+
+            emit!(ParameterUpdated {
+                parameter_type: "execution_delay".to_string(),
+                old_value: old_delay as u64,
+                new_value: hours as u64,
+                updated_by,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+        ParameterUpdateType::TypeRules {
+            proposal_type,
+            rules,
+        } => {
+            let old_rules = governance_config.proposal_type_rules[proposal_type as usize];
+            governance_config.proposal_type_rules[proposal_type as usize] = rules;
+
+            let changes = [
+                (
+                    "quorum",
+                    old_rules.quorum_percentage as u64,
+                    rules.quorum_percentage as u64,
+                ),
+                (
+                    "passing_threshold",
+                    old_rules.passing_threshold_percentage as u64,
+                    rules.passing_threshold_percentage as u64,
+                ),
+                (
+                    "execution_delay",
+                    old_rules.execution_delay_hours as u64,
+                    rules.execution_delay_hours as u64,
+                ),
+                (
+                    "min_voting_period",
+                    old_rules.min_voting_period_hours as u64,
+                    rules.min_voting_period_hours as u64,
+                ),
+                (
+                    "deposit",
+                    old_rules.deposit_percentage as u64,
+                    rules.deposit_percentage as u64,
+                ),
+                (
+                    "abstain_policy",
+                    old_rules.abstain_policy as u64,
+                    rules.abstain_policy as u64,
+                ),
+            ];
+            for (field, old_value, new_value) in changes {
+                if old_value != new_value {
+                    emit!(ParameterUpdated {
+                        parameter_type: format!("{:?}.{}", proposal_type, field),
+                        old_value,
+                        new_value,
+                        updated_by,
+                        timestamp: clock.unix_timestamp,
+                    });
+                }
+            }
+        }
+        ParameterUpdateType::PassingThreshold { percentage } => {
+            let old_threshold = governance_config
+                .proposal_settings
+                .passing_threshold_percentage;
+            governance_config
+                .proposal_settings
+                .passing_threshold_percentage = percentage;
+
+            emit!(ParameterUpdated {
+                parameter_type: "passing_threshold".to_string(),
+                old_value: old_threshold as u64,
+                new_value: percentage as u64,
+                updated_by,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+        ParameterUpdateType::SpamCooldown { hours } => {
+            let old_cooldown = governance_config
+                .parameter_bounds
+                .spam_prevention_cooldown_hours;
+            governance_config
+                .parameter_bounds
+                .spam_prevention_cooldown_hours = hours;
+
+            emit!(ParameterUpdated {
+                parameter_type: "spam_cooldown".to_string(),
+                old_value: old_cooldown as u64,
+                new_value: hours as u64,
+                updated_by,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+        ParameterUpdateType::VetoThreshold { percentage } => {
+            let old_threshold = governance_config
+                .proposal_settings
+                .veto_threshold_percentage;
+            governance_config
+                .proposal_settings
+                .veto_threshold_percentage = percentage;
+
+            emit!(ParameterUpdated {
+                parameter_type: "veto_threshold".to_string(),
+                old_value: old_threshold as u64,
+                new_value: percentage as u64,
+                updated_by,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+        ParameterUpdateType::OptimisticObjectionThreshold { percentage } => {
+            let old_threshold = governance_config
+                .proposal_settings
+                .optimistic_objection_percentage;
+            governance_config
+                .proposal_settings
+                .optimistic_objection_percentage = percentage;
+
+            emit!(ParameterUpdated {
+                parameter_type: "optimistic_objection_threshold".to_string(),
+                old_value: old_threshold as u64,
+                new_value: percentage as u64,
+                updated_by,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+        ParameterUpdateType::VoteExtension {
+            hours,
+            max_extensions,
+        } => {
+            let settings = &mut governance_config.proposal_settings;
+            let old_hours = settings.vote_extension_hours;
+            let old_max_extensions = settings.max_vote_extensions;
+            settings.vote_extension_hours = hours;
+            settings.max_vote_extensions = max_extensions;
+
+            emit!(ParameterUpdated {
+                parameter_type: "vote_extension_hours".to_string(),
+                old_value: old_hours as u64,
+                new_value: hours as u64,
+                updated_by,
+                timestamp: clock.unix_timestamp,
+            });
+            emit!(ParameterUpdated {
+                parameter_type: "max_vote_extensions".to_string(),
+                old_value: old_max_extensions as u64,
+                new_value: max_extensions as u64,
+                updated_by,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+        ParameterUpdateType::PrivateVoting {
+            reveal_period_hours,
+            unrevealed_penalty_hours,
+        } => {
+            let settings = &mut governance_config.proposal_settings;
+            let old_reveal_period = settings.reveal_period_hours;
+            let old_penalty = settings.unrevealed_vote_penalty_hours;
+            settings.reveal_period_hours = reveal_period_hours;
+            settings.unrevealed_vote_penalty_hours = unrevealed_penalty_hours;
+
+            emit!(ParameterUpdated {
+                parameter_type: "reveal_period".to_string(),
+                old_value: old_reveal_period as u64,
+                new_value: reveal_period_hours as u64,
+                updated_by,
+                timestamp: clock.unix_timestamp,
+            });
+            emit!(ParameterUpdated {
+                parameter_type: "unrevealed_vote_penalty".to_string(),
+                old_value: old_penalty as u64,
+                new_value: unrevealed_penalty_hours as u64,
+                updated_by,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+        ParameterUpdateType::QuorumDecayFloor { percentage } => {
+            let old_floor = governance_config
+                .proposal_settings
+                .quorum_decay_floor_percentage;
+            governance_config
+                .proposal_settings
+                .quorum_decay_floor_percentage = percentage;
+
+            emit!(ParameterUpdated {
+                parameter_type: "quorum_decay_floor".to_string(),
+                old_value: old_floor as u64,
+                new_value: percentage as u64,
+                updated_by,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+        ParameterUpdateType::ProposalThreshold { mode, percentage } => {
+            let settings = &mut governance_config.proposal_settings;
+            let old_mode = settings.proposal_threshold_mode;
+            let old_threshold = settings.proposal_threshold_percentage;
+            settings.proposal_threshold_mode = mode;
+            if mode == ProposalThresholdMode::VotingPowerShare {
+                settings.proposal_threshold_percentage = percentage;
+            }
+
+            emit!(ParameterUpdated {
+                parameter_type: "proposal_threshold_mode".to_string(),
+                old_value: old_mode as u64,
+                new_value: mode as u64,
+                updated_by,
+                timestamp: clock.unix_timestamp,
+            });
+            emit!(ParameterUpdated {
+                parameter_type: "proposal_threshold".to_string(),
+                old_value: old_threshold as u64,
+                new_value: settings.proposal_threshold_percentage as u64,
+                updated_by,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+    }
+
+    governance_config.last_updated = clock.unix_timestamp;
+
+    Ok(())
+}
+
+fn verify_proposal_instructions_executed(
+    proposal_instructions: &Account<ProposalInstructions>,
+) -> Result<()> {
+    require!(
+        proposal_instructions.executed_count as usize == proposal_instructions.instructions.len(),
+        ErrorCode::ProposalInstructionsPending
+    );
+
+    Ok(())
+}
+
+fn calculate_hybrid_voting_power(
+    stake_amount: u64,
+    stake_duration_days: u32,
+    time_multipliers: &TimeMultipliers,
+) -> u64 {
+    let tokens = stake_amount / 1_000_000;
+
+    let base_power = if tokens <= 100_000 {
+        tokens
+    } else {
+        100_000 + ((tokens - 100_000) as f64).sqrt() as u64
+    };
+
+    let time_multiplier = if stake_duration_days > time_multipliers.tier_3_days as u32 {
+        time_multipliers.tier_3_multiplier
+    } else if stake_duration_days > time_multipliers.tier_2_days {
+        time_multipliers.tier_2_multiplier
+    } else if stake_duration_days > time_multipliers.tier_1_days {
+        time_multipliers.tier_1_multiplier
+    } else {
+        100
+    };
+
+    (base_power * time_multiplier as u64) / 100
+}
+
+fn transfer_deposit_to_proposer<'info>(
+    deposit_escrow: &InterfaceAccount<'info, TokenAccount>,
+    proposer_token_account: &InterfaceAccount<'info, TokenAccount>,
+    token_mint: &InterfaceAccount<'info, Mint>,
+    program_authority: &UncheckedAccount<'info>,
+    token_program: &Interface<'info, TokenInterface>,
+    amount: u64,
+    authority_bump: u8,
+) -> Result<()> {
+    let authority_bump_arr = &[authority_bump];
+    let authority_seeds = &[PROGRAM_AUTHORITY_SEED, authority_bump_arr.as_ref()];
+    let signer_seeds = &[&authority_seeds[..]];
+
+    transfer_checked(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            TransferChecked {
+                from: deposit_escrow.to_account_info(),
+                mint: token_mint.to_account_info(),
+                to: proposer_token_account.to_account_info(),
+                authority: program_authority.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+        token_mint.decimals,
+    )?;
+
+    Ok(())
+}
+
+/// Validates proposal content against the config and returns what gets stored:
+/// `(description, metadata_uri, content_hash)`.
+fn validate_proposal_content(
+    governance_config: &GovernanceConfig,
+    title: &str,
+    content: ProposalContent,
+    proposal_type: ProposalType,
+    execution_data: &[u8],
+    optimistic: bool,
+) -> Result<(String, String, [u8; 32])> {
+    require!(
+        title.len() <= MAX_TITLE_LENGTH,
+        ErrorCode::ProposalTitleTooLong
+    );
+    let stored_content = match content {
+        ProposalContent::Inline { description } => {
+            require!(
+                description.len() <= MAX_DESCRIPTION_LENGTH,
+                ErrorCode::ProposalDescriptionTooLong
+            );
+            let content_hash = hash(description.as_bytes()).to_bytes();
+            (description, String::new(), content_hash)
+        }
+        ProposalContent::Offchain { uri, content_hash } => {
+            require!(
+                !uri.is_empty() && uri.len() <= MAX_METADATA_URI_LENGTH,
+                ErrorCode::InvalidMetadataUri
+            );
+            require!(content_hash != [0; 32], ErrorCode::InvalidContentHash);
+            (String::new(), uri, content_hash)
+        }
+    };
+    require!(
+        execution_data.len() <= MAX_EXECUTION_DATA_LENGTH,
+        ErrorCode::ExecutionDataTooLarge
+    );
+
+    let payload = ExecutionPayload::decode(execution_data)?;
+    require!(
+        payload.proposal_type() == proposal_type,
+        ErrorCode::ExecutionPayloadTypeMismatch
+    );
+    payload.validate(governance_config)?;
+
+    // Optimistic mode is limited to routine proposals: text signals and small grants
+    if optimistic {
+        match &payload {
+            ExecutionPayload::Text { .. } => {}
+            ExecutionPayload::TreasuryTransfer { amount, .. } => require!(
+                *amount <= governance_config.proposal_settings.max_optimistic_treasury_amount,
+                ErrorCode::OptimisticTreasuryAmountTooLarge
+            ),
+            _ => return Err(ErrorCode::OptimisticProposalNotAllowed.into()),
+        }
+    }
+
+    Ok(stored_content)
+}
+
+fn close_vote_records<'info>(
+    proposal: &ProposalAccount,
+    governance_config: &GovernanceConfig,
+    now: i64,
+    accounts: &'info [AccountInfo<'info>],
+) -> Result<()> {
+    let pairs = accounts.chunks_exact(2);
+    require!(
+        pairs.remainder().is_empty(),
+        ErrorCode::InvalidVoteRecordAccounts
+    );
+
+    for pair in pairs {
+        let vote_record: Account<'info, VoteRecord> = Account::try_from(&pair[0])?;
+        let voter = &pair[1];
+
+        require!(
+            vote_record.proposal_id == proposal.proposal_id && vote_record.voter == voter.key(),
+            ErrorCode::InvalidVoteRecordAccounts
+        );
+        // Same guard as close_vote_record: unrevealed commitments outlive the penalty window
+        if proposal.is_private && vote_record.vote_choice.is_none() {
+            require_gte!(
+                now,
+                governance_config.unrevealed_penalty_ends_at(proposal)?,
+                ErrorCode::TokensLockedForGovernance
+            );
+        }
+
+        vote_record.close(voter.clone())?;
+    }
+
+    Ok(())
+}
+
+/// Checks shared by public and private (committed) votes.
+fn validate_vote_eligibility(
+    proposal: &ProposalAccount,
+    user_staking_account: &UserStakingAccount,
+    governance_account: &GovernanceAccount,
+    now: i64,
+) -> Result<()> {
+    require!(
+        proposal.status == ProposalStatus::Active,
+        ErrorCode::ProposalNotActive
+    );
+    require!(
+        now < proposal.voting_ends_at,
+        ErrorCode::VotingPeriodEnded
+    );
+
+    let stake_duration = now - user_staking_account.timestamp;
+    require_gte!(
+        stake_duration,
+        MIN_STAKE_DURATION_FOR_VOTING,
+        ErrorCode::InsufficientStakeDurationToVote
+    );
+    require!(
+        governance_account.voting_power_cache > 0,
+        ErrorCode::VotingPowerNotCalculated
+    );
+
+    Ok(())
+}
+
+/// Adds a vote to the proposal tally.
+fn record_vote(
+    proposal: &mut ProposalAccount,
+    vote_choice: VoteChoice,
+    voting_power: u64,
+) -> Result<()> {
+    match vote_choice {
+        VoteChoice::Yes => {
+            proposal.yes_votes = proposal
+                .yes_votes
+                .checked_add(voting_power)
+                .ok_or(ErrorCode::InvalidAmount)?;
+        }
+        VoteChoice::No => {
+            proposal.no_votes = proposal
+                .no_votes
+                .checked_add(voting_power)
+                .ok_or(ErrorCode::InvalidAmount)?;
+        }
+        VoteChoice::Abstain => {
+            proposal.abstain_votes = proposal
+                .abstain_votes
+                .checked_add(voting_power)
+                .ok_or(ErrorCode::InvalidAmount)?;
+        }
+        VoteChoice::NoWithVeto => {
+            // Counts as No for the passing threshold, tracked separately for the veto check
+            proposal.no_votes = proposal
+                .no_votes
+                .checked_add(voting_power)
+                .ok_or(ErrorCode::InvalidAmount)?;
+            proposal.veto_votes = proposal
+                .veto_votes
+                .checked_add(voting_power)
+                .ok_or(ErrorCode::InvalidAmount)?;
+        }
+    }
+
+    proposal.total_voters = proposal
+        .total_voters
+        .checked_add(1)
+        .ok_or(ErrorCode::InvalidAmount)?;
+
+    Ok(())
+}
+
+/// Commitment for a private vote: SHA-256 over the proposal, voter, the voting
+/// power at commit time, the choice and a voter-chosen salt.
+pub fn vote_commitment(
+    proposal_id: u64,
+    voter: &Pubkey,
+    voting_power: u64,
+    vote_choice: VoteChoice,
+    salt: &[u8; 32],
+) -> [u8; 32] {
+    hashv(&[
+        &proposal_id.to_le_bytes(),
+        voter.as_ref(),
+        &voting_power.to_le_bytes(),
+        &[vote_choice as u8],
+        salt,
+    ])
+    .to_bytes()
+}
+
+/// Locks a voter's stake until `VOTE_LOCK_PERIOD` after the tally of `proposal`
+/// closes, without shortening a longer lock from another proposal.
+fn extend_stake_lock(
+    governance_account: &mut GovernanceAccount,
+    proposal: &ProposalAccount,
+) -> Result<()> {
+    let lock_end = proposal
+        .tally_ends_at()
+        .checked_add(VOTE_LOCK_PERIOD)
+        .ok_or(ErrorCode::InvalidAmount)?;
+    governance_account.stake_lock_end = governance_account.stake_lock_end.max(lock_end);
+
+    Ok(())
+}
+
+/// Applies `extend_stake_lock` to (vote_record, governance_account) pairs of
+/// earlier voters on `proposal`.
+fn extend_vote_locks<'info>(
+    proposal: &ProposalAccount,
+    accounts: &'info [AccountInfo<'info>],
+    program_id: &Pubkey,
+) -> Result<()> {
+    let pairs = accounts.chunks_exact(2);
+    require!(
+        pairs.remainder().is_empty(),
+        ErrorCode::InvalidVoteRecordAccounts
+    );
+
+    for pair in pairs {
+        let vote_record: Account<'info, VoteRecord> = Account::try_from(&pair[0])?;
+        let mut governance_account: Account<'info, GovernanceAccount> =
+            Account::try_from(&pair[1])?;
+
+        let vote_record_key = Pubkey::create_program_address(
+            &[
+                VOTE_SEED,
+                &proposal.proposal_id.to_le_bytes(),
+                vote_record.voter.as_ref(),
+                &[vote_record.bump],
+            ],
+            program_id,
+        )
+        .map_err(|_| ErrorCode::InvalidVoteRecordAccounts)?;
+        let governance_key = Pubkey::create_program_address(
+            &[
+                GOVERNANCE_SEED,
+                vote_record.voter.as_ref(),
+                &[governance_account.bump],
+            ],
+            program_id,
+        )
+        .map_err(|_| ErrorCode::InvalidVoteRecordAccounts)?;
+
+        require!(
+            *pair[0].key == vote_record_key
+                && *pair[1].key == governance_key
+                && pair[1].is_writable
+                && vote_record.proposal_id == proposal.proposal_id
+                && governance_account.staker == vote_record.voter,
+            ErrorCode::InvalidVoteRecordAccounts
+        );
+
+        extend_stake_lock(&mut governance_account, proposal)?;
+        governance_account.exit(program_id)?;
+    }
+
+    Ok(())
+}
+
+/// Outcome of finalizing `proposal` now with the votes cast so far (`Passed`,
+/// `Failed` or `Vetoed`) and whether quorum was met. Shared by `finalize_proposal`
+/// and `is_passing` so the two always agree.
+fn tally_outcome(
+    proposal: &ProposalAccount,
+    governance_config: &GovernanceConfig,
+    total_voting_power: u64,
+    now: i64,
+) -> Result<(ProposalStatus, bool)> {
+    let settings = &governance_config.proposal_settings;
+    let rules = governance_config.rules_for(proposal.proposal_type);
+
+    let base_quorum = if proposal.proposal_type == ProposalType::Emergency {
+        governance_config.get_effective_quorum(rules.quorum_percentage, now)
+    } else {
+        rules.quorum_percentage
+    };
+    let quorum_votes = proposal.quorum_votes()?;
+
+    // Thresholds are compared exactly in basis points (see `meets_basis_points`).
+    // Ties: quorum and the passing threshold are met when reached exactly, while
+    // veto and optimistic objections must strictly exceed theirs. Passing thresholds
+    // are always above 50%, so an even Yes/No split fails.
+    let quorum_met = meets_basis_points(
+        quorum_votes,
+        total_voting_power,
+        governance_config.decayed_quorum(base_quorum, proposal),
+    );
+
+    // A veto also overturns an optimistic pass, provided quorum was met. Without
+    // quorum a veto has no weight, the same as for a regular proposal.
+    if quorum_met
+        && exceeds_basis_points(
+            proposal.veto_votes,
+            quorum_votes,
+            settings.veto_threshold_percentage,
+        )
+    {
+        return Ok((ProposalStatus::Vetoed, quorum_met));
+    }
+
+    // Optimistic proposals pass unless No votes (including vetoes) exceed the
+    // objection threshold, in which case they fail outright
+    if proposal.is_optimistic {
+        let objected = exceeds_basis_points(
+            proposal.no_votes,
+            total_voting_power,
+            settings.optimistic_objection_percentage,
+        );
+        let outcome = if objected {
+            ProposalStatus::Failed
+        } else {
+            ProposalStatus::Passed
+        };
+        return Ok((outcome, quorum_met));
+    }
+
+    let threshold_votes = proposal.threshold_votes()?;
+    let passed = quorum_met
+        && threshold_votes > 0
+        && meets_basis_points(
+            proposal.yes_votes,
+            threshold_votes,
+            rules.passing_threshold_percentage,
+        );
+
+    let outcome = if passed {
+        ProposalStatus::Passed
+    } else {
+        ProposalStatus::Failed
+    };
+
+    Ok((outcome, quorum_met))
+}
+
+/// Whether `proposal` would pass if it were finalized now with the votes cast so far.
+fn is_passing(
+    proposal: &ProposalAccount,
+    governance_config: &GovernanceConfig,
+    total_voting_power: u64,
+    now: i64,
+) -> Result<bool> {
+    let (outcome, _) = tally_outcome(proposal, governance_config, total_voting_power, now)?;
+
+    Ok(outcome == ProposalStatus::Passed)
+}
+
+/// True once no allocation of the `remaining` votes can change the result of
+/// `finalize_proposal`: the veto check is settled either way, and the passing
+/// threshold is either met even if every remaining vote is No, or missed even
+/// if every remaining vote is Yes.
+fn is_outcome_decided(
+    proposal: &ProposalAccount,
+    remaining: u64,
+    settings: &ProposalSettings,
+    rules: &ProposalTypeRules,
+) -> Result<bool> {
+    let max_total = proposal
+        .quorum_votes()?
+        .checked_add(remaining)
+        .ok_or(ErrorCode::InvalidAmount)?;
+    let max_veto = proposal
+        .veto_votes
+        .checked_add(remaining)
+        .ok_or(ErrorCode::InvalidAmount)?;
+
+    let veto_threshold = settings.veto_threshold_percentage;
+    let veto_locked_in = exceeds_basis_points(proposal.veto_votes, max_total, veto_threshold);
+    let veto_impossible = !exceeds_basis_points(max_veto, max_total, veto_threshold);
+
+    let threshold_votes = proposal.threshold_votes()?;
+    let max_threshold_votes = threshold_votes
+        .checked_add(remaining)
+        .ok_or(ErrorCode::InvalidAmount)?;
+    let max_yes = proposal
+        .yes_votes
+        .checked_add(remaining)
+        .ok_or(ErrorCode::InvalidAmount)?;
+
+    let passing_threshold = rules.passing_threshold_percentage;
+    let pass_locked_in = threshold_votes > 0
+        && meets_basis_points(proposal.yes_votes, max_threshold_votes, passing_threshold);
+    let fail_locked_in = !meets_basis_points(max_yes, max_threshold_votes, passing_threshold);
+
+    Ok(veto_locked_in || (veto_impossible && (pass_locked_in || fail_locked_in)))
+}
+
+/// `part / total >= threshold` for a threshold in basis points, cross-multiplied in
+/// u128 so nothing is rounded away (a 6667 bp threshold really needs 66.67%).
+/// An empty `total` meets any threshold.
+fn meets_basis_points(part: u64, total: u64, threshold: u16) -> bool {
+    part as u128 * BASIS_POINTS_DENOMINATOR as u128 >= total as u128 * threshold as u128
+}
+
+/// `part / total > threshold` for a threshold in basis points; the strict
+/// counterpart of `meets_basis_points`. An empty `part` never exceeds a threshold.
+fn exceeds_basis_points(part: u64, total: u64, threshold: u16) -> bool {
+    part as u128 * BASIS_POINTS_DENOMINATOR as u128 > total as u128 * threshold as u128
+}
+
+#[allow(clippy::too_many_arguments)]
+fn slash_deposit<'info>(
+    deposit_escrow: &InterfaceAccount<'info, TokenAccount>,
+    treasury_account: Option<&InterfaceAccount<'info, TokenAccount>>,
+    token_mint: &InterfaceAccount<'info, Mint>,
+    program_authority: &UncheckedAccount<'info>,
+    token_program: &Interface<'info, TokenInterface>,
+    amount: u64,
+    burn_deposit: bool,
+    authority_bump: u8,
+) -> Result<()> {
+    let authority_bump_arr = &[authority_bump];
+    let authority_seeds = &[PROGRAM_AUTHORITY_SEED, authority_bump_arr.as_ref()];
+    let signer_seeds = &[&authority_seeds[..]];
+
+    if burn_deposit {
+        burn(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                Burn {
+                    mint: token_mint.to_account_info(),
+                    from: deposit_escrow.to_account_info(),
+                    authority: program_authority.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            amount,
+        )?;
+    } else {
+        let treasury_account = treasury_account.ok_or(ErrorCode::MissingTreasuryAccount)?;
+
+        transfer_checked(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                TransferChecked {
+                    from: deposit_escrow.to_account_info(),
+                    mint: token_mint.to_account_info(),
+                    to: treasury_account.to_account_info(),
+                    authority: program_authority.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            amount,
+            token_mint.decimals,
+        )?;
+    }
+
+    Ok(())
+}
+
+impl ExecutionPayload {
+    /// Serializes the payload with its version prefix, as stored in `execution_data`.
+    pub fn encode(&self) -> Result<Vec<u8>> {
+        let mut data = vec![EXECUTION_PAYLOAD_VERSION];
+        self.serialize(&mut data).map_err(|_| ErrorCode::InvalidExecutionData)?;
+        Ok(data)
+    }
+
+    /// Parses versioned `execution_data`; trailing bytes are rejected.
+    pub fn decode(data: &[u8]) -> Result<Self> {
+        let (version, body) = data
+            .split_first()
+            .ok_or(ErrorCode::InvalidExecutionData)?;
+        require!(
+            *version == EXECUTION_PAYLOAD_VERSION,
+            ErrorCode::UnsupportedExecutionPayloadVersion
+        );
+
+        Self::try_from_slice(body).map_err(|_| ErrorCode::InvalidExecutionData.into())
+    }
+
+    pub fn proposal_type(&self) -> ProposalType {
+        match self {
+            ExecutionPayload::Text { .. } => ProposalType::Text,
+            ExecutionPayload::TreasuryTransfer { .. } => ProposalType::TreasuryTransfer,
+            ExecutionPayload::ParameterUpdate { .. } => ProposalType::ParameterUpdate,
+            ExecutionPayload::CustomInstructions => ProposalType::CustomInstructions,
+            ExecutionPayload::Emergency => ProposalType::Emergency,
+        }
+    }
+
+    pub fn validate(&self, governance_config: &GovernanceConfig) -> Result<()> {
+        match self {
+            ExecutionPayload::Text { .. }
+            | ExecutionPayload::CustomInstructions
+            | ExecutionPayload::Emergency => Ok(()),
+            ExecutionPayload::TreasuryTransfer { recipient, amount } => {
+                require!(*amount > 0, ErrorCode::InvalidAmount);
+                require!(
+                    *recipient != Pubkey::default(),
+                    ErrorCode::InvalidRecipientAccount
+                );
+                Ok(())
+            }
+            ExecutionPayload::ParameterUpdate { update } => {
+                governance_config.validate_parameter_bounds(update)
+            }
+        }
+    }
+}
+
+#[account]
+pub struct StakingPool {
+    pub authority: Pubkey,
+    pub authority_bump: u8,
+    pub initializer: Pubkey,
+    pub total_staked_amount: u64,
+    pub mint_address: Pubkey,
+    pub escrow_account: Pubkey,
+    pub bump: u8,
+    pub created_at: i64,
+    pub is_active: bool,
+    pub token_price_usd_micro: u64,
+    pub price_last_updated: i64,
+}
+
+impl StakingPool {
+    /// Total voting power at the base multiplier (one vote per whole staked token).
+    /// Quorum, objections and the share-based proposal threshold are all measured
+    /// against this figure.
+    pub fn total_voting_power(&self) -> u64 {
+        self.total_staked_amount / 1_000_000
+    }
+}
+
+#[account]
+pub struct UserStakingAccount {
+    pub staker: Pubkey,
+    pub staked_amount: u64,
+    pub timestamp: i64,
+    pub last_updated: i64,
+    pub bump: u8,
+}
+
+#[account]
+pub struct GovernanceAccount {
+    pub staker: Pubkey,
+    pub participation_count: u32,
+    pub last_vote_timestamp: i64,
+    pub stake_lock_end: i64,
+    pub voting_power_cache: u64,
+    pub created_at: i64,
+    pub bump: u8,
+    pub active_proposals: u8,
+    pub proposals_created: u32,
+    pub proposals_failed: u32,
+    pub proposals_slashed: u32,
+    pub last_proposal_timestamp: i64,
+    pub pledged_stake: u64, // stake backing open proposals and sponsorships
+}
+
+impl GovernanceAccount {
+    pub const SPACE: usize = 8 + 98;
+
+    /// Deserializes an account that may predate the fields appended since the
+    /// baseline layout. Missing trailing bytes are read as zero.
+    pub fn try_deserialize_legacy(data: &[u8]) -> Result<Self> {
+        let mut padded = data.to_vec();
+        if padded.len() < Self::SPACE {
+            padded.resize(Self::SPACE, 0);
+        }
+        Self::try_deserialize(&mut &padded[..])
+    }
+
+    /// Number of past proposals counted against the proposer when escalating the
+    /// required stake. Slashed proposals weigh double compared to plain failures.
+    pub fn proposer_history(&self) -> u32 {
+        self.proposals_failed
+            .saturating_add(self.proposals_slashed.saturating_mul(2))
+    }
+}
+
+#[account]
+pub struct ProposalAccount {
+    pub proposal_id: u64,
+    pub proposer: Pubkey,
+    pub title: String,
+    pub description: String,
+    pub proposal_type: ProposalType,
+    pub status: ProposalStatus,
+    pub execution_data: Vec<u8>,
+    pub voting_period_hours: u32,
+    pub created_at: i64,
+    pub voting_ends_at: i64,
+    pub finalized_at: i64,
+    pub executed_at: i64,
+    pub timelock_end: i64,
+    pub yes_votes: u64,
+    pub no_votes: u64,
+    pub abstain_votes: u64,
+    pub total_voters: u32,
+    pub deposit_amount: u64,
+    pub deposit_refunded: bool,
+    pub bump: u8,
+    pub veto_votes: u64, // subset of no_votes cast as NoWithVeto
+    pub is_optimistic: bool,
+    pub metadata_uri: String,    // empty for inline descriptions
+    pub content_hash: [u8; 32], // SHA-256 of the description, inline or off-chain
+    pub required_stake: u64,
+    pub backing_stake: u64, // proposer's counted stake plus sponsor pledges
+    pub sponsor_count: u8,  // sponsorships not yet settled
+    pub abstain_policy: AbstainPolicy,
+    pub last_vote_at: i64,
+    pub vote_extensions: u8,
+    pub is_private: bool,
+    pub reveal_ends_at: i64,        // end of the reveal phase for private (commit-reveal) votes
+    pub voting_power_snapshot: u64, // total voting power when voting opened
+    pub proposer_pledge: u64,       // proposer's own stake locked until finalization
+    pub reserved: [u8; 3],
+}
+
+impl ProposalAccount {
+    pub fn space(title: &str, content: &ProposalContent, execution_data: &[u8]) -> usize {
+        let (description_len, uri_len) = match content {
+            ProposalContent::Inline { description } => (description.len(), 0),
+            ProposalContent::Offchain { uri, .. } => (0, uri.len()),
+        };
+
+        let variable_size =
+            4 + title.len() + 4 + description_len + 4 + execution_data.len() + 4 + uri_len;
+
+        8 + 220 + variable_size
+    }
+
+    /// When voting opened: creation for regular proposals, activation for drafts.
+    pub fn voting_starts_at(&self) -> i64 {
+        self.voting_ends_at - self.voting_period_hours as i64 * SECONDS_PER_HOUR
+    }
+
+    /// When the tally is complete: the end of the reveal phase for private votes,
+    /// otherwise the end of voting.
+    pub fn tally_ends_at(&self) -> i64 {
+        if self.is_private {
+            self.reveal_ends_at
+        } else {
+            self.voting_ends_at
+        }
+    }
+
+    /// Votes counted toward quorum (and the veto share) under the abstain policy.
+    pub fn quorum_votes(&self) -> Result<u64> {
+        let yes_no = self
+            .yes_votes
+            .checked_add(self.no_votes)
+            .ok_or(ErrorCode::InvalidAmount)?;
+
+        match self.abstain_policy {
+            AbstainPolicy::Excluded => Ok(yes_no),
+            AbstainPolicy::QuorumOnly | AbstainPolicy::QuorumAndThreshold => Ok(yes_no
+                .checked_add(self.abstain_votes)
+                .ok_or(ErrorCode::InvalidAmount)?),
+        }
+    }
+
+    /// Votes the Yes share is measured against for the passing threshold.
+    pub fn threshold_votes(&self) -> Result<u64> {
+        let yes_no = self
+            .yes_votes
+            .checked_add(self.no_votes)
+            .ok_or(ErrorCode::InvalidAmount)?;
+
+        match self.abstain_policy {
+            AbstainPolicy::QuorumAndThreshold => Ok(yes_no
+                .checked_add(self.abstain_votes)
+                .ok_or(ErrorCode::InvalidAmount)?),
+            AbstainPolicy::QuorumOnly | AbstainPolicy::Excluded => Ok(yes_no),
+        }
+    }
+
+    pub fn finalized_event(&self, quorum_met: bool) -> ProposalFinalized {
+        ProposalFinalized {
+            proposal_id: self.proposal_id,
+            status: self.status,
+            yes_votes: self.yes_votes,
+            no_votes: self.no_votes,
+            abstain_votes: self.abstain_votes,
+            veto_votes: self.veto_votes,
+            abstain_policy: self.abstain_policy,
+            quorum_met,
+            timestamp: self.finalized_at,
+        }
+    }
+
+    /// Finalized for good, with the deposit already refunded or slashed.
+    pub fn is_settled(&self) -> bool {
+        matches!(
+            self.status,
+            ProposalStatus::Failed
+                | ProposalStatus::Executed
+                | ProposalStatus::Cancelled
+                | ProposalStatus::ExecutionFailed
+                | ProposalStatus::EmergencyCancelled
+                | ProposalStatus::Expired
+                | ProposalStatus::Vetoed
+        ) && self.deposit_refunded
+    }
+}
+
+#[account]
+pub struct ProposalSponsorship {
+    pub proposal_id: u64,
+    pub sponsor: Pubkey,
+    pub stake_amount: u64,
+    pub deposit_amount: u64,
+    pub bump: u8,
+}
+
+#[account]
+pub struct ProposalSummary {
+    pub proposal_id: u64,
+    pub proposer: Pubkey,
+    pub proposal_type: ProposalType,
+    pub status: ProposalStatus,
+    pub yes_votes: u64,
+    pub no_votes: u64,
+    pub abstain_votes: u64,
+    pub veto_votes: u64,
+    pub total_voters: u32,
+    pub created_at: i64,
+    pub finalized_at: i64,
+    pub executed_at: i64,
+    pub content_hash: [u8; 32],
+    pub closed_at: i64,
+    pub bump: u8,
+}
+
+#[account]
+pub struct VoteRecord {
+    pub voter: Pubkey,
+    pub proposal_id: u64,
+    pub vote_choice: Option<VoteChoice>, // None until a private vote is revealed
+    pub voting_power: u64,
+    pub voted_at: i64,
+    pub bump: u8,
+    pub commitment: [u8; 32], // private votes only; zero for public votes
+}
+
+#[account]
+pub struct ProposalInstructions {
+    pub proposal_id: u64,
+    pub proposer: Pubkey,
+    pub instructions: Vec<ProposalInstruction>,
+    pub executed_count: u16,
+    pub bump: u8,
+}
+
+impl ProposalInstructions {
+    pub fn space(instructions: &[ProposalInstruction]) -> usize {
+        let instructions_size: usize = instructions
+            .iter()
+            .map(|instruction| {
+                32 + 4 + instruction.accounts.len() * 34 + 4 + instruction.data.len() + 8
+            })
+            .sum();
+
+        8 + 8 + 32 + 4 + instructions_size + 2 + 1
+    }
+}
+
+#[account]
+pub struct GovernanceConfig {
+    pub authority: Pubkey,                     // 32 bytes
+    pub staking_pool: Pubkey,                  // 32 bytes
+    pub parameter_bounds: ParameterBounds,     // 120 bytes
+    pub time_multipliers: TimeMultipliers,     // 16 bytes
+    pub proposal_settings: ProposalSettings,   // 64 bytes
+    pub execution_settings: ExecutionSettings, // 32 bytes
+    pub emergency_settings: EmergencySettings, // 52 bytes
+    pub proposal_type_rules: [ProposalTypeRules; PROPOSAL_TYPE_COUNT], // 75 bytes
+    pub version: u8,                           // 1 byte
+    pub created_at: i64,                       // 8 bytes
+    pub last_updated: i64,                     // 8 bytes
+    pub bump: u8,                              // 1 byte
+    pub next_proposal_id: u64,                 // 8 bytes
+}
+// Total: 449 bytes
+
+impl GovernanceConfig {
+    pub fn calculate_proposal_stake_requirement(
+        &self,
+        proposer_history: u32,
+        total_voting_power: u64,
+    ) -> Result<u64> {
+        // Voting power counts whole tokens; stake requirements are in base units
+        let total_power_stake = total_voting_power.saturating_mul(1_000_000);
+        let share_mode = self.proposal_settings.proposal_threshold_mode
+            == ProposalThresholdMode::VotingPowerShare;
+
+        let base_stake = if share_mode {
+            let share = (total_power_stake as u128)
+                .checked_mul(self.proposal_settings.proposal_threshold_percentage as u128)
+                .ok_or(ErrorCode::InvalidAmount)?
+                / BASIS_POINTS_DENOMINATOR as u128;
+            share as u64
+        } else {
+            self.proposal_settings.base_proposal_stake
+        };
+        let escalation_factor = self.proposal_settings.stake_escalation_factor;
+        let max_multiplier = self.proposal_settings.max_escalation_multiplier;
+
+        let escalation = (proposer_history as u64).saturating_mul(escalation_factor as u64) / 100;
+        let escalation_multiplier = escalation.saturating_add(100).min(max_multiplier as u64);
+
+        let required_stake = base_stake.saturating_mul(escalation_multiplier) / 100;
+
+        // A share of total voting power is bounded by the whole of it, not by the
+        // absolute stake bounds that the share mode replaces
+        if share_mode {
+            Ok(required_stake.min(total_power_stake))
+        } else {
+            Ok(required_stake.clamp(
+                self.parameter_bounds.min_proposal_stake_base,
+                self.parameter_bounds.max_proposal_stake_base,
+            ))
+        }
+    }
+
+    /// Upper bound on the voting power that can ever be cast, assuming every staked
+    /// token votes at the highest time multiplier.
+    pub fn max_voting_power(&self, total_staked_tokens: u64) -> Result<u64> {
+        let multipliers = &self.time_multipliers;
+        let max_multiplier = multipliers
+            .tier_1_multiplier
+            .max(multipliers.tier_2_multiplier)
+            .max(multipliers.tier_3_multiplier)
+            .max(100);
+
+        let max_power = total_staked_tokens
+            .checked_mul(max_multiplier as u64)
+            .ok_or(ErrorCode::InvalidAmount)?
+            / 100;
+
+        Ok(max_power)
+    }
+
+    /// Rules that apply to `proposal_type`, with unset (zero) fields resolved to the
+    /// global proposal and execution settings.
+    pub fn rules_for(&self, proposal_type: ProposalType) -> ProposalTypeRules {
+        let rules = &self.proposal_type_rules[proposal_type as usize];
+        let settings = &self.proposal_settings;
+
+        ProposalTypeRules {
+            quorum_percentage: match rules.quorum_percentage {
+                0 => settings.default_quorum_percentage,
+                percentage => percentage,
+            },
+            passing_threshold_percentage: match rules.passing_threshold_percentage {
+                0 => settings.passing_threshold_percentage,
+                percentage => percentage,
+            },
+            execution_delay_hours: match rules.execution_delay_hours {
+                0 => self.execution_settings.execution_delay_hours,
+                hours => hours,
+            },
+            min_voting_period_hours: match rules.min_voting_period_hours {
+                0 => self.parameter_bounds.min_voting_period_hours,
+                hours => hours,
+            },
+            deposit_percentage: match rules.deposit_percentage {
+                0 => settings.proposal_fee_percentage,
+                percentage => percentage,
+            },
+            abstain_policy: rules.abstain_policy,
+        }
+    }
+
+    pub fn calculate_proposal_deposit(
+        &self,
+        proposal_type: ProposalType,
+        required_stake: u64,
+    ) -> Result<u64> {
+        let deposit = required_stake
+            .checked_mul(self.rules_for(proposal_type).deposit_percentage as u64)
+            .ok_or(ErrorCode::InvalidAmount)?
+            / BASIS_POINTS_DENOMINATOR;
+
+        Ok(deposit)
+    }
+
+    pub fn is_emergency_active(&self, now: i64) -> bool {
+        let expires_at = self.emergency_settings.emergency_activated_at
+            + self.emergency_settings.emergency_cooldown_hours as i64 * SECONDS_PER_HOUR;
+
+        self.emergency_settings.emergency_override_enabled && now < expires_at
+    }
+
+    /// Quorum required for `proposal` once the decay curve is applied: it falls linearly
+    /// from `quorum` at the start of voting to the configured floor at `voting_ends_at`,
+    /// read at the time of the last vote. A zero floor disables the curve.
+    pub fn decayed_quorum(&self, quorum: u16, proposal: &ProposalAccount) -> u16 {
+        let floor = self.proposal_settings.quorum_decay_floor_percentage.min(quorum);
+        if floor == 0 || floor == quorum {
+            return quorum;
+        }
+
+        let starts_at = proposal.voting_starts_at();
+        let duration = proposal.voting_ends_at.saturating_sub(starts_at);
+        if duration <= 0 {
+            return quorum;
+        }
+
+        let elapsed = proposal
+            .last_vote_at
+            .saturating_sub(starts_at)
+            .clamp(0, duration);
+        let decay = (quorum - floor) as i128 * elapsed as i128 / duration as i128;
+
+        quorum - decay as u16
+    }
+
+    pub fn reveal_ends_at(&self, voting_ends_at: i64) -> Result<i64> {
+        let reveal_period = self.proposal_settings.reveal_period_hours as i64 * SECONDS_PER_HOUR;
+
+        let reveal_ends_at = voting_ends_at
+            .checked_add(reveal_period)
+            .ok_or(ErrorCode::InvalidAmount)?;
+
+        Ok(reveal_ends_at)
+    }
+
+    /// End of the window in which `penalize_unrevealed_vote` can still extend a
+    /// voter's stake lock for a private vote left unrevealed.
+    pub fn unrevealed_penalty_ends_at(&self, proposal: &ProposalAccount) -> Result<i64> {
+        let penalty_period =
+            self.proposal_settings.unrevealed_vote_penalty_hours as i64 * SECONDS_PER_HOUR;
+
+        let penalty_ends_at = proposal
+            .reveal_ends_at
+            .checked_add(penalty_period)
+            .ok_or(ErrorCode::InvalidAmount)?;
+
+        Ok(penalty_ends_at)
+    }
+
+    pub fn get_effective_quorum(&self, base_quorum: u16, now: i64) -> u16 {
+        if self.is_emergency_active(now) {
+            // The reduction never takes quorum below the configured minimum
+            base_quorum
+                .saturating_sub(self.emergency_settings.emergency_quorum_reduction)
+                .max(self.parameter_bounds.min_quorum_percentage)
+        } else {
+            base_quorum
+        }
+    }
+
+    pub fn validate_parameter_bounds(&self, update_type: &ParameterUpdateType) -> Result<()> {
+        match update_type {
+            ParameterUpdateType::QuorumThreshold { percentage } => {
+                require!(
+                    *percentage >= self.parameter_bounds.min_quorum_percentage
+                        && *percentage <= self.parameter_bounds.max_quorum_percentage,
+                    ErrorCode::InvalidParameterValue
+                );
+            }
+            ParameterUpdateType::VotingPeriod { hours } => {
+                require!(
+                    *hours >= self.parameter_bounds.min_voting_period_hours
+                        && *hours <= self.parameter_bounds.max_voting_period_hours,
+                    ErrorCode::InvalidParameterValue
+                );
+            }
+            ParameterUpdateType::ProposalStake { amount } => {
+                require!(
+                    *amount >= self.parameter_bounds.min_proposal_stake_base
+                        && *amount <= self.parameter_bounds.max_proposal_stake_base,
+                    ErrorCode::InvalidParameterValue
+                );
+            }
+            ParameterUpdateType::TimeMultiplier { tier, multiplier } => {
+                require!(
+                    *tier >= 1 && *tier <= 3 && *multiplier >= 100 && *multiplier <= 500,
+                    ErrorCode::InvalidParameterValue
+                );
+            }
+            ParameterUpdateType::TreasuryLimit { percentage } => {
+                require!(
+                    *percentage <= self.parameter_bounds.max_treasury_withdrawal_percentage,
+                    ErrorCode::InvalidParameterValue
+                );
+            }
+            ParameterUpdateType::ExecutionDelay { hours } => {
+                require!(
+                    *hours >= self.parameter_bounds.min_execution_delay_hours
+                        && *hours <= self.parameter_bounds.max_execution_delay_hours,
+                    ErrorCode::InvalidParameterValue
+                );
+            }
+            ParameterUpdateType::PassingThreshold { percentage } => {
+                require!(
+                    *percentage > 5000 && *percentage as u64 <= BASIS_POINTS_DENOMINATOR,
+                    ErrorCode::InvalidParameterValue
+                );
+            }
+            ParameterUpdateType::TypeRules { rules, .. } => {
+                self.validate_type_rules(rules)?;
+            }
+            ParameterUpdateType::SpamCooldown { hours } => {
+                require!(
+                    *hours <= MAX_SPAM_PREVENTION_COOLDOWN_HOURS,
+                    ErrorCode::InvalidParameterValue
+                );
+            }
+            ParameterUpdateType::OptimisticObjectionThreshold { percentage } => {
+                require!(
+                    *percentage > 0 && *percentage as u64 <= BASIS_POINTS_DENOMINATOR,
+                    ErrorCode::InvalidParameterValue
+                );
+            }
+            ParameterUpdateType::VoteExtension {
+                hours,
+                max_extensions,
+            } => {
+                require!(
+                    *hours <= self.parameter_bounds.max_voting_period_hours
+                        && *max_extensions <= MAX_VOTE_EXTENSIONS,
+                    ErrorCode::InvalidParameterValue
+                );
+            }
+            ParameterUpdateType::PrivateVoting {
+                reveal_period_hours,
+                unrevealed_penalty_hours,
+            } => {
+                require!(
+                    *reveal_period_hours > 0
+                        && *reveal_period_hours <= self.parameter_bounds.max_voting_period_hours
+                        && *unrevealed_penalty_hours <= MAX_UNREVEALED_VOTE_PENALTY_HOURS,
+                    ErrorCode::InvalidParameterValue
+                );
+            }
+            ParameterUpdateType::QuorumDecayFloor { percentage } => {
+                require!(
+                    *percentage <= self.parameter_bounds.max_quorum_percentage,
+                    ErrorCode::InvalidParameterValue
+                );
+            }
+            ParameterUpdateType::ProposalThreshold { mode, percentage } => {
+                require!(
+                    *mode == ProposalThresholdMode::AbsoluteStake
+                        || (*percentage > 0 && *percentage as u64 <= BASIS_POINTS_DENOMINATOR),
+                    ErrorCode::InvalidParameterValue
+                );
+            }
+            ParameterUpdateType::VetoThreshold { percentage } => {
+                require!(
+                    *percentage > 0 && *percentage as u64 <= BASIS_POINTS_DENOMINATOR,
+                    ErrorCode::InvalidParameterValue
+                );
+            }
+            ParameterUpdateType::EmergencySettings {
+                quorum_reduction,
+                voting_period,
+            } => {
+                let emergency_quorum = self.rules_for(ProposalType::Emergency).quorum_percentage;
+                require!(
+                    *quorum_reduction as u64 <= BASIS_POINTS_DENOMINATOR
+                        && emergency_quorum.saturating_sub(*quorum_reduction)
+                            >= self.parameter_bounds.min_quorum_percentage
+                        && *voting_period > 0
+                        && *voting_period <= self.parameter_bounds.max_voting_period_hours,
+                    ErrorCode::InvalidParameterValue
+                );
+            }
+        }
+        Ok(())
+    }
+
+    // MIN_EXECUTION_DELAY_HOURS is zero on devnet, which makes its check always true there
+    #[allow(clippy::absurd_extreme_comparisons)]
+    pub fn validate_invariants(&self) -> Result<()> {
+        let bounds = &self.parameter_bounds;
+        let settings = &self.proposal_settings;
+        let multipliers = &self.time_multipliers;
+        let execution_delay = self.execution_settings.execution_delay_hours;
+
+        require!(
+            bounds.min_quorum_percentage <= bounds.max_quorum_percentage
+                && bounds.min_proposal_stake_base <= bounds.max_proposal_stake_base
+                && bounds.min_voting_period_hours <= bounds.max_voting_period_hours
+                && bounds.min_execution_delay_hours <= bounds.max_execution_delay_hours
+                && bounds.min_execution_delay_hours >= MIN_EXECUTION_DELAY_HOURS,
+            ErrorCode::ParameterBoundsValidationFailed
+        );
+        require!(
+            settings.default_quorum_percentage >= bounds.min_quorum_percentage
+                && settings.default_quorum_percentage <= bounds.max_quorum_percentage
+                && settings.base_proposal_stake >= bounds.min_proposal_stake_base
+                && settings.base_proposal_stake <= bounds.max_proposal_stake_base
+                && settings.default_voting_period_hours >= bounds.min_voting_period_hours
+                && settings.default_voting_period_hours <= bounds.max_voting_period_hours
+                && execution_delay >= bounds.min_execution_delay_hours
+                && execution_delay <= bounds.max_execution_delay_hours,
+            ErrorCode::ParameterBoundsValidationFailed
+        );
+        require!(
+            multipliers.tier_1_days < multipliers.tier_2_days
+                && multipliers.tier_2_days < multipliers.tier_3_days as u32
+                && multipliers.tier_1_multiplier <= multipliers.tier_2_multiplier
+                && multipliers.tier_2_multiplier <= multipliers.tier_3_multiplier,
+            ErrorCode::ParameterBoundsValidationFailed
+        );
+        for rules in self.proposal_type_rules.iter() {
+            self.validate_type_rules(rules)
+                .map_err(|_| ErrorCode::ParameterBoundsValidationFailed)?;
+        }
+
+        Ok(())
+    }
+
+    /// Every overridden (non-zero) rule must stay within the same bounds as its
+    /// global counterpart.
+    fn validate_type_rules(&self, rules: &ProposalTypeRules) -> Result<()> {
+        let bounds = &self.parameter_bounds;
+
+        require!(
+            rules.quorum_percentage == 0
+                || (rules.quorum_percentage >= bounds.min_quorum_percentage
+                    && rules.quorum_percentage <= bounds.max_quorum_percentage),
+            ErrorCode::InvalidParameterValue
+        );
+        require!(
+            rules.passing_threshold_percentage == 0
+                || (rules.passing_threshold_percentage > 5000
+                    && rules.passing_threshold_percentage as u64 <= BASIS_POINTS_DENOMINATOR),
+            ErrorCode::InvalidParameterValue
+        );
+        require!(
+            rules.execution_delay_hours == 0
+                || (rules.execution_delay_hours >= bounds.min_execution_delay_hours
+                    && rules.execution_delay_hours <= bounds.max_execution_delay_hours),
+            ErrorCode::InvalidParameterValue
+        );
+        require!(
+            rules.min_voting_period_hours == 0
+                || (rules.min_voting_period_hours >= bounds.min_voting_period_hours
+                    && rules.min_voting_period_hours <= bounds.max_voting_period_hours),
+            ErrorCode::InvalidParameterValue
+        );
+        require!(
+            rules.deposit_percentage as u64 <= BASIS_POINTS_DENOMINATOR,
+            ErrorCode::InvalidParameterValue
+        );
+
+        Ok(())
+    }
+}
+
+// ============================================================================
+// EVENTS
+// ============================================================================
+
+#[event]
+pub struct GovernanceConfigInitialized {
     pub authority: Pubkey,
-    pub authority_bump: u8,
-    pub initializer: Pubkey,
-    pub total_staked_amount: u64,
-    pub mint_address: Pubkey,
-    pub escrow_account: Pubkey,
-    pub bump: u8,
-    pub created_at: i64,
-    pub is_active: bool,
-    pub token_price_usd_micro: u64,
-    pub price_last_updated: i64,
+    pub staking_pool: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ParameterUpdated {
+    pub parameter_type: String,
+    pub old_value: u64,
+    pub new_value: u64,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct EmergencySettingsUpdated {
+    pub quorum_reduction: u16,
+    pub voting_period: u32,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VotingPowerCalculated {
+    pub user: Pubkey,
+    pub voting_power: u64,
+    pub stake_amount: u64,
+    pub stake_duration_days: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProposalValidationPerformed {
+    pub user: Pubkey,
+    pub required_stake: u64,
+    pub has_sufficient_stake: bool,
+    pub meets_duration_requirement: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct EmergencyModeToggled {
+    pub enabled: bool,
+    pub triggered_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VotingExtended {
+    pub proposal_id: u64,
+    pub voting_ends_at: i64,
+    pub vote_extensions: u8,
+    pub triggered_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProposalFinalized {
+    pub proposal_id: u64,
+    pub status: ProposalStatus,
+    pub yes_votes: u64,
+    pub no_votes: u64,
+    pub abstain_votes: u64,
+    pub veto_votes: u64,
+    pub abstain_policy: AbstainPolicy,
+    pub quorum_met: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VoteCommitted {
+    pub proposal_id: u64,
+    pub voter: Pubkey,
+    pub voting_power: u64,
+    pub timestamp: i64,
+}
+
+// ============================================================================
+// ACCOUNT CONTEXTS
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct InitializeStakingPool<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + 163,
+        seeds = [STAKING_POOL_SEED],
+        bump
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+
+    #[account(seeds = [PROGRAM_AUTHORITY_SEED], bump)]
+    /// CHECK: Program authority PDA
+    pub program_authority: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = admin,
+        token::mint = token_mint,
+        token::authority = program_authority,
+        seeds = [STAKING_POOL_ESCROW_SEED, staking_pool.key().as_ref()],
+        bump
+    )]
+    pub escrow_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(constraint = token_mint.to_account_info().owner == &spl_token_2022::ID @ ErrorCode::InvalidTokenProgram)]
+    pub token_mint: InterfaceAccount<'info, Mint>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+// Add the context
+#[derive(Accounts)]
+pub struct InitializeTreasury<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(seeds = [STAKING_POOL_SEED], bump = staking_pool.bump)]
+    pub staking_pool: Account<'info, StakingPool>,
+
+    #[account(seeds = [PROGRAM_AUTHORITY_SEED], bump = staking_pool.authority_bump)]
+    /// CHECK: Program authority PDA
+    pub program_authority: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = admin,
+        token::mint = token_mint,
+        token::authority = program_authority,
+        seeds = [TREASURY_SEED, staking_pool.key().as_ref()],
+        bump
+    )]
+    pub treasury_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(constraint = token_mint.to_account_info().owner == &spl_token_2022::ID @ ErrorCode::InvalidTokenProgram)]
+    pub token_mint: InterfaceAccount<'info, Mint>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct Stake<'info> {
+    #[account(mut)]
+    pub staker: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [STAKING_POOL_SEED],
+        bump = staking_pool.bump,
+        constraint = staking_pool.is_active @ ErrorCode::PoolNotActive,
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+
+    #[account(
+        init_if_needed,
+        payer = staker,
+        space = 8 + 57,
+        seeds = [USER_STAKE_SEED, staker.key().as_ref()],
+        bump,
+    )]
+    pub user_staking_account: Account<'info, UserStakingAccount>,
+
+    #[account(seeds = [PROGRAM_AUTHORITY_SEED], bump = staking_pool.authority_bump)]
+    /// CHECK: Program authority PDA
+    pub program_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [STAKING_POOL_ESCROW_SEED, staking_pool.key().as_ref()],
+        bump,
+        constraint = escrow_token_account.key() == staking_pool.escrow_account @ ErrorCode::InvalidTokenMint,
+    )]
+    pub escrow_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = staker,
+        associated_token::token_program = token_program
+    )]
+    pub staker_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(constraint = token_mint.to_account_info().owner == &spl_token_2022::ID @ ErrorCode::InvalidTokenProgram)]
+    pub token_mint: InterfaceAccount<'info, Mint>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct Unstake<'info> {
+    #[account(mut)]
+    pub staker: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [STAKING_POOL_SEED],
+        bump = staking_pool.bump,
+        constraint = staking_pool.is_active @ ErrorCode::PoolNotActive,
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+
+    #[account(
+        mut,
+        seeds = [USER_STAKE_SEED, staker.key().as_ref()],
+        bump = user_staking_account.bump,
+        constraint = user_staking_account.staker == staker.key() @ ErrorCode::UnauthorizedStaker,
+    )]
+    pub user_staking_account: Account<'info, UserStakingAccount>,
+
+    #[account(seeds = [GOVERNANCE_SEED, staker.key().as_ref()], bump)]
+    /// CHECK: Staker's governance PDA; may not exist yet, deserialized in the handler
+    pub governance_account: UncheckedAccount<'info>,
+
+    #[account(seeds = [PROGRAM_AUTHORITY_SEED], bump = staking_pool.authority_bump)]
+    /// CHECK: Program authority PDA
+    pub program_authority: UncheckedAccount<'info>,
+
+    #[account(mut, seeds = [STAKING_POOL_ESCROW_SEED, staking_pool.key().as_ref()], bump)]
+    pub escrow_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = staker,
+        associated_token::token_program = token_program
+    )]
+    pub staker_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(constraint = token_mint.to_account_info().owner == &spl_token_2022::ID @ ErrorCode::InvalidTokenProgram)]
+    pub token_mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeGovernanceAccount<'info> {
+    #[account(mut)]
+    pub staker: Signer<'info>,
+
+    #[account(init, payer = staker, space = GovernanceAccount::SPACE, seeds = [GOVERNANCE_SEED, staker.key().as_ref()], bump)]
+    pub governance_account: Account<'info, GovernanceAccount>,
+
+    pub system_program: Program<'info, System>,
 }
 
-#[account]
-pub struct UserStakingAccount {
-    pub staker: Pubkey,
-    pub staked_amount: u64,
-    pub timestamp: i64,
-    pub last_updated: i64,
-    pub bump: u8,
+#[derive(Accounts)]
+pub struct InitializeGovernanceConfig<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STAKING_POOL_SEED],
+        bump = staking_pool.bump,
+        constraint = staking_pool.initializer == authority.key() @ ErrorCode::Unauthorized,
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + 449,
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    pub system_program: Program<'info, System>,
 }
 
-#[account]
-pub struct GovernanceAccount {
-    pub staker: Pubkey,
-    pub participation_count: u32,
-    pub last_vote_timestamp: i64,
-    pub stake_lock_end: i64,
-    pub voting_power_cache: u64,
-    pub created_at: i64,
-    pub bump: u8,
+#[derive(Accounts)]
+pub struct UpdateGovernanceParameter<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(mut, seeds = [GOVERNANCE_CONFIG_SEED], bump = governance_config.bump)]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    // OPTIONAL - only needed for governance-originated updates
+    #[account(
+        mut,
+        seeds = [PROPOSAL_SEED, governance_proposal.proposal_id.to_le_bytes().as_ref()],
+        bump = governance_proposal.bump,
+    )]
+    pub governance_proposal: Option<Account<'info, ProposalAccount>>,
+
+    // OPTIONAL - deposit refund accounts, required with a governance proposal
+    #[account(seeds = [STAKING_POOL_SEED], bump = staking_pool.bump)]
+    pub staking_pool: Option<Account<'info, StakingPool>>,
+
+    #[account(seeds = [PROGRAM_AUTHORITY_SEED], bump)]
+    /// CHECK: Program authority PDA
+    pub program_authority: Option<UncheckedAccount<'info>>,
+
+    #[account(mut, seeds = [PROPOSAL_ESCROW_SEED], bump)]
+    pub deposit_escrow_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub proposer_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub deposit_token_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
 }
 
-#[account]
-pub struct ProposalAccount {
-    pub proposal_id: u64,
-    pub proposer: Pubkey,
-    pub title: String,
-    pub description: String,
-    pub proposal_type: ProposalType,
-    pub status: ProposalStatus,
-    pub execution_data: Vec<u8>,
-    pub voting_period_days: u8,
-    pub created_at: i64,
-    pub voting_ends_at: i64,
-    pub finalized_at: i64,
-    pub executed_at: i64,
-    pub timelock_end: i64,
-    pub yes_votes: u64,
-    pub no_votes: u64,
-    pub abstain_votes: u64,
-    pub total_voters: u32,
-    pub deposit_amount: u64,
-    pub deposit_refunded: bool,
-    pub bump: u8,
-    pub reserved: [u8; 32],
+#[derive(Accounts)]
+pub struct ValidateProposalRequirements<'info> {
+    pub user: Signer<'info>,
+
+    #[account(seeds = [GOVERNANCE_CONFIG_SEED], bump = governance_config.bump)]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    #[account(
+        seeds = [USER_STAKE_SEED, user.key().as_ref()],
+        bump = user_staking_account.bump,
+        constraint = user_staking_account.staker == user.key() @ ErrorCode::UnauthorizedStaker,
+    )]
+    pub user_staking_account: Account<'info, UserStakingAccount>,
+
+    #[account(
+        seeds = [GOVERNANCE_SEED, user.key().as_ref()],
+        bump = user_governance_account.bump,
+        constraint = user_governance_account.staker == user.key() @ ErrorCode::UnauthorizedStaker,
+    )]
+    pub user_governance_account: Account<'info, GovernanceAccount>,
+
+    #[account(seeds = [STAKING_POOL_SEED], bump = staking_pool.bump)]
+    pub staking_pool: Account<'info, StakingPool>,
 }
 
-#[account]
-pub struct VoteRecord {
-    pub voter: Pubkey,
-    pub proposal_id: u64,
-    pub vote_choice: VoteChoice,
-    pub voting_power: u64,
-    pub voted_at: i64,
-    pub bump: u8,
+#[derive(Accounts)]
+pub struct ToggleEmergencyMode<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(mut, seeds = [GOVERNANCE_CONFIG_SEED], bump = governance_config.bump)]
+    pub governance_config: Account<'info, GovernanceConfig>,
+}
+
+#[derive(Accounts)]
+pub struct CalculateVotingPower<'info> {
+    pub staker: Signer<'info>,
+
+    #[account(
+        seeds = [USER_STAKE_SEED, staker.key().as_ref()],
+        bump = user_staking_account.bump,
+        constraint = user_staking_account.staker == staker.key() @ ErrorCode::UnauthorizedStaker,
+    )]
+    pub user_staking_account: Account<'info, UserStakingAccount>,
+
+    #[account(mut, seeds = [GOVERNANCE_SEED, staker.key().as_ref()], bump = governance_account.bump)]
+    pub governance_account: Account<'info, GovernanceAccount>,
+
+    #[account(seeds = [GOVERNANCE_CONFIG_SEED], bump = governance_config.bump)]
+    pub governance_config: Account<'info, GovernanceConfig>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeProposalEscrow<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(seeds = [STAKING_POOL_SEED], bump = staking_pool.bump)]
+    pub staking_pool: Account<'info, StakingPool>,
+
+    #[account(seeds = [PROGRAM_AUTHORITY_SEED], bump = staking_pool.authority_bump)]
+    /// CHECK: Program authority PDA
+    pub program_authority: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = admin,
+        token::mint = token_mint,
+        token::authority = program_authority,
+        seeds = [PROPOSAL_ESCROW_SEED],
+        bump
+    )]
+    pub proposal_escrow: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(constraint = token_mint.to_account_info().owner == &spl_token_2022::ID @ ErrorCode::InvalidTokenProgram)]
+    pub token_mint: InterfaceAccount<'info, Mint>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+#[instruction(
+    proposal_id: u64,
+    title: String,
+    content: ProposalContent,
+    proposal_type: ProposalType,
+    execution_data: Vec<u8>,
+)]
+pub struct CreateProposal<'info> {
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    #[account(
+        seeds = [USER_STAKE_SEED, proposer.key().as_ref()],
+        bump = proposer_staking_account.bump,
+        constraint = proposer_staking_account.staker == proposer.key() @ ErrorCode::UnauthorizedStaker,
+    )]
+    pub proposer_staking_account: Account<'info, UserStakingAccount>,
+
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_SEED, proposer.key().as_ref()],
+        bump = proposer_governance_account.bump,
+        constraint = proposer_governance_account.staker == proposer.key() @ ErrorCode::UnauthorizedStaker,
+    )]
+    pub proposer_governance_account: Account<'info, GovernanceAccount>,
+
+    #[account(mut, seeds = [GOVERNANCE_CONFIG_SEED], bump = governance_config.bump)]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    #[account(
+        init,
+        payer = proposer,
+        space = ProposalAccount::space(&title, &content, &execution_data),
+        seeds = [PROPOSAL_SEED, proposal_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub proposal_account: Account<'info, ProposalAccount>,
+
+    #[account(seeds = [STAKING_POOL_SEED], bump = staking_pool.bump)]
+    pub staking_pool: Account<'info, StakingPool>,
+
+    #[account(seeds = [PROGRAM_AUTHORITY_SEED], bump = staking_pool.authority_bump)]
+    /// CHECK: Program authority PDA
+    pub program_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = deposit_token_mint,
+        associated_token::authority = proposer,
+        associated_token::token_program = token_program,
+    )]
+    pub proposer_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(mut, seeds = [PROPOSAL_ESCROW_SEED], bump)]
+    pub deposit_escrow_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(constraint = deposit_token_mint.to_account_info().owner == &spl_token_2022::ID @ ErrorCode::InvalidTokenProgram)]
+    pub deposit_token_mint: InterfaceAccount<'info, Mint>,
+
+    // OPTIONAL - only needed for custom instruction proposals
+    #[account(seeds = [PROPOSAL_INSTRUCTIONS_SEED, proposal_id.to_le_bytes().as_ref(), proposer.key().as_ref()], bump = proposal_instructions.bump)]
+    pub proposal_instructions: Option<Account<'info, ProposalInstructions>>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64, instructions: Vec<ProposalInstruction>)]
+pub struct CreateProposalInstructions<'info> {
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    #[account(
+        init,
+        payer = proposer,
+        space = ProposalInstructions::space(&instructions),
+        seeds = [PROPOSAL_INSTRUCTIONS_SEED, proposal_id.to_le_bytes().as_ref(), proposer.key().as_ref()],
+        bump
+    )]
+    pub proposal_instructions: Account<'info, ProposalInstructions>,
+
+    #[account(seeds = [STAKING_POOL_SEED], bump = staking_pool.bump)]
+    pub staking_pool: Account<'info, StakingPool>,
+
+    #[account(seeds = [PROPOSAL_ESCROW_SEED], bump)]
+    pub deposit_escrow_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub system_program: Program<'info, System>,
 }
 
-#[account]
-pub struct GovernanceConfig {
-    pub authority: Pubkey,
-    pub quorum_percentage: u64,
-    pub passing_threshold: u64,
-    pub timelock_duration: i64,
-    pub min_stake_to_propose: u64,
-    pub proposal_deposit: u64,
-    pub created_at: i64,
-    pub last_updated: i64,
-    pub bump: u8,
-    pub reserved: [u8; 32],
-}
+#[derive(Accounts)]
+#[instruction(vote_choice: VoteChoice)]
+pub struct CastVote<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    #[account(
+        seeds = [USER_STAKE_SEED, voter.key().as_ref()],
+        bump = user_staking_account.bump,
+        constraint = user_staking_account.staker == voter.key() @ ErrorCode::UnauthorizedStaker,
+    )]
+    pub user_staking_account: Account<'info, UserStakingAccount>,
+
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_SEED, voter.key().as_ref()],
+        bump = governance_account.bump,
+        constraint = governance_account.staker == voter.key() @ ErrorCode::UnauthorizedStaker,
+    )]
+    pub governance_account: Account<'info, GovernanceAccount>,
+
+    #[account(mut, seeds = [PROPOSAL_SEED, proposal_account.proposal_id.to_le_bytes().as_ref()], bump = proposal_account.bump)]
+    pub proposal_account: Account<'info, ProposalAccount>,
+
+    #[account(
+        init,
+        payer = voter,
+        space = 8 + 91,
+        seeds = [VOTE_SEED, proposal_account.proposal_id.to_le_bytes().as_ref(), voter.key().as_ref()],
+        bump
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+
+    #[account(seeds = [GOVERNANCE_CONFIG_SEED], bump = governance_config.bump)]
+    pub governance_config: Account<'info, GovernanceConfig>,
 
-// ============================================================================
-// ACCOUNT CONTEXTS
-// ============================================================================
+    #[account(seeds = [STAKING_POOL_SEED], bump = staking_pool.bump)]
+    pub staking_pool: Account<'info, StakingPool>,
+
+    pub system_program: Program<'info, System>,
+}
 
 #[derive(Accounts)]
-pub struct InitializeStakingPool<'info> {
+pub struct FinalizeProposal<'info> {
     #[account(mut)]
-    pub admin: Signer<'info>,
+    pub finalizer: Signer<'info>,
+
+    #[account(mut, seeds = [PROPOSAL_SEED, proposal_account.proposal_id.to_le_bytes().as_ref()], bump = proposal_account.bump)]
+    pub proposal_account: Account<'info, ProposalAccount>,
 
     #[account(
-        init,
-        payer = admin,
-        space = 8 + 163,
-        seeds = [STAKING_POOL_SEED],
-        bump
+        mut,
+        seeds = [GOVERNANCE_SEED, proposal_account.proposer.as_ref()],
+        bump = proposer_governance_account.bump,
     )]
+    pub proposer_governance_account: Account<'info, GovernanceAccount>,
+
+    #[account(seeds = [STAKING_POOL_SEED], bump = staking_pool.bump)]
     pub staking_pool: Account<'info, StakingPool>,
 
-    #[account(seeds = [PROGRAM_AUTHORITY_SEED], bump)]
+    #[account(seeds = [GOVERNANCE_CONFIG_SEED], bump = governance_config.bump)]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    #[account(seeds = [PROGRAM_AUTHORITY_SEED], bump = staking_pool.authority_bump)]
     /// CHECK: Program authority PDA
     pub program_authority: UncheckedAccount<'info>,
 
-    #[account(
-        init,
-        payer = admin,
-        token::mint = token_mint,
-        token::authority = program_authority,
-        seeds = [STAKING_POOL_ESCROW_SEED, staking_pool.key().as_ref()],
-        bump
-    )]
-    pub escrow_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(mut, seeds = [PROPOSAL_ESCROW_SEED], bump)]
+    pub deposit_escrow_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(mut, constraint = proposer_token_account.owner == proposal_account.proposer @ ErrorCode::InvalidProposerAccount)]
+    pub proposer_token_account: InterfaceAccount<'info, TokenAccount>,
 
     #[account(constraint = token_mint.to_account_info().owner == &spl_token_2022::ID @ ErrorCode::InvalidTokenProgram)]
     pub token_mint: InterfaceAccount<'info, Mint>,
 
-    pub system_program: Program<'info, System>,
     pub token_program: Interface<'info, TokenInterface>,
-    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    // OPTIONAL - only needed when a vetoed deposit is sent to the treasury
+    #[account(mut, seeds = [TREASURY_SEED, staking_pool.key().as_ref()], bump)]
+    pub treasury_account: Option<InterfaceAccount<'info, TokenAccount>>,
 }
 
-// Add the context
 #[derive(Accounts)]
-pub struct InitializeTreasury<'info> {
+pub struct ExecuteProposal<'info> {
     #[account(mut)]
-    pub admin: Signer<'info>,
+    pub executor: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PROPOSAL_SEED, &proposal_account.proposal_id.to_le_bytes()],
+        bump = proposal_account.bump,
+    )]
+    pub proposal_account: Account<'info, ProposalAccount>,
 
     #[account(seeds = [STAKING_POOL_SEED], bump = staking_pool.bump)]
     pub staking_pool: Account<'info, StakingPool>,
@@ -931,239 +4335,353 @@ pub struct InitializeTreasury<'info> {
     pub program_authority: UncheckedAccount<'info>,
 
     #[account(
-        init,
-        payer = admin,
-        token::mint = token_mint,
-        token::authority = program_authority,
-        seeds = [TREASURY_SEED, staking_pool.key().as_ref()],
-        bump
+        mut,
+        seeds = [PROPOSAL_ESCROW_SEED],
+        bump,
     )]
-    pub treasury_account: InterfaceAccount<'info, TokenAccount>,
+    pub deposit_escrow_account: InterfaceAccount<'info, TokenAccount>,
 
-    #[account(constraint = token_mint.to_account_info().owner == &spl_token_2022::ID @ ErrorCode::InvalidTokenProgram)]
-    pub token_mint: InterfaceAccount<'info, Mint>,
+    #[account(mut)]
+    pub proposer_token_account: InterfaceAccount<'info, TokenAccount>,
 
-    pub system_program: Program<'info, System>,
-    pub token_program: Interface<'info, TokenInterface>,
+    pub deposit_token_mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program_for_deposit: Interface<'info, TokenInterface>,
+
+    // REQUIRED - governance config is needed for parameter updates
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = governance_config.bump,
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    // OPTIONAL - only needed for treasury transfers
+    #[account(mut)]
+    /// CHECK: Optional treasury account for treasury transfer proposals
+    pub treasury_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    /// CHECK: Optional recipient account for treasury transfer proposals
+    pub recipient_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: Optional token mint for treasury transfers
+    pub token_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: Optional token program for treasury transfers
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    // OPTIONAL - only needed for custom instruction proposals
+    #[account(
+        seeds = [PROPOSAL_INSTRUCTIONS_SEED, &proposal_account.proposal_id.to_le_bytes(), proposal_account.proposer.as_ref()],
+        bump = proposal_instructions.bump,
+    )]
+    pub proposal_instructions: Option<Account<'info, ProposalInstructions>>,
 }
 
 #[derive(Accounts)]
-pub struct Stake<'info> {
+pub struct ExecuteProposalInstruction<'info> {
     #[account(mut)]
-    pub staker: Signer<'info>,
+    pub executor: Signer<'info>,
 
     #[account(
-        mut,
-        seeds = [STAKING_POOL_SEED],
-        bump = staking_pool.bump,
-        constraint = staking_pool.is_active @ ErrorCode::PoolNotActive,
+        seeds = [PROPOSAL_SEED, &proposal_account.proposal_id.to_le_bytes()],
+        bump = proposal_account.bump,
     )]
-    pub staking_pool: Account<'info, StakingPool>,
+    pub proposal_account: Account<'info, ProposalAccount>,
 
     #[account(
-        init_if_needed,
-        payer = staker,
-        space = 8 + 57,
-        seeds = [USER_STAKE_SEED, staker.key().as_ref()],
-        bump,
+        mut,
+        seeds = [PROPOSAL_INSTRUCTIONS_SEED, &proposal_account.proposal_id.to_le_bytes(), proposal_account.proposer.as_ref()],
+        bump = proposal_instructions.bump,
     )]
-    pub user_staking_account: Account<'info, UserStakingAccount>,
+    pub proposal_instructions: Account<'info, ProposalInstructions>,
+
+    #[account(seeds = [STAKING_POOL_SEED], bump = staking_pool.bump)]
+    pub staking_pool: Account<'info, StakingPool>,
 
     #[account(seeds = [PROGRAM_AUTHORITY_SEED], bump = staking_pool.authority_bump)]
-    /// CHECK: Program authority PDA
+    /// CHECK: Program authority PDA, signs the stored instructions
     pub program_authority: UncheckedAccount<'info>,
 
+    #[account(seeds = [GOVERNANCE_CONFIG_SEED], bump = governance_config.bump)]
+    pub governance_config: Account<'info, GovernanceConfig>,
+}
+
+#[derive(Accounts)]
+#[instruction(title: String, content: ProposalContent, execution_data: Vec<u8>)]
+pub struct UpdateDraftProposal<'info> {
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
     #[account(
         mut,
-        seeds = [STAKING_POOL_ESCROW_SEED, staking_pool.key().as_ref()],
+        seeds = [PROPOSAL_SEED, &proposal_account.proposal_id.to_le_bytes()],
+        bump = proposal_account.bump,
+        constraint = proposal_account.proposer == proposer.key() @ ErrorCode::NotProposer,
+        realloc = ProposalAccount::space(&title, &content, &execution_data),
+        realloc::payer = proposer,
+        realloc::zero = false,
+    )]
+    pub proposal_account: Account<'info, ProposalAccount>,
+
+    #[account(seeds = [GOVERNANCE_CONFIG_SEED], bump = governance_config.bump)]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SponsorProposal<'info> {
+    #[account(mut)]
+    pub sponsor: Signer<'info>,
+
+    #[account(
+        seeds = [USER_STAKE_SEED, sponsor.key().as_ref()],
+        bump = sponsor_staking_account.bump,
+        constraint = sponsor_staking_account.staker == sponsor.key() @ ErrorCode::UnauthorizedStaker,
+    )]
+    pub sponsor_staking_account: Account<'info, UserStakingAccount>,
+
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_SEED, sponsor.key().as_ref()],
+        bump = sponsor_governance_account.bump,
+        constraint = sponsor_governance_account.staker == sponsor.key() @ ErrorCode::UnauthorizedStaker,
+    )]
+    pub sponsor_governance_account: Account<'info, GovernanceAccount>,
+
+    #[account(seeds = [GOVERNANCE_CONFIG_SEED], bump = governance_config.bump)]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    #[account(
+        mut,
+        seeds = [PROPOSAL_SEED, &proposal_account.proposal_id.to_le_bytes()],
+        bump = proposal_account.bump,
+    )]
+    pub proposal_account: Account<'info, ProposalAccount>,
+
+    #[account(
+        init,
+        payer = sponsor,
+        space = 8 + 57,
+        seeds = [SPONSORSHIP_SEED, &proposal_account.proposal_id.to_le_bytes(), sponsor.key().as_ref()],
         bump,
-        constraint = escrow_token_account.key() == staking_pool.escrow_account @ ErrorCode::InvalidTokenMint,
     )]
-    pub escrow_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub sponsorship: Account<'info, ProposalSponsorship>,
 
     #[account(
         mut,
-        associated_token::mint = token_mint,
-        associated_token::authority = staker,
-        associated_token::token_program = token_program
+        associated_token::mint = deposit_token_mint,
+        associated_token::authority = sponsor,
+        associated_token::token_program = token_program,
     )]
-    pub staker_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub sponsor_token_account: InterfaceAccount<'info, TokenAccount>,
 
-    #[account(constraint = token_mint.to_account_info().owner == &spl_token_2022::ID @ ErrorCode::InvalidTokenProgram)]
-    pub token_mint: InterfaceAccount<'info, Mint>,
+    #[account(mut, seeds = [PROPOSAL_ESCROW_SEED], bump)]
+    pub deposit_escrow_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(constraint = deposit_token_mint.to_account_info().owner == &spl_token_2022::ID @ ErrorCode::InvalidTokenProgram)]
+    pub deposit_token_mint: InterfaceAccount<'info, Mint>,
 
     pub system_program: Program<'info, System>,
     pub token_program: Interface<'info, TokenInterface>,
 }
 
 #[derive(Accounts)]
-pub struct Unstake<'info> {
+pub struct SettleSponsorship<'info> {
     #[account(mut)]
-    pub staker: Signer<'info>,
+    pub caller: Signer<'info>,
 
     #[account(
         mut,
-        seeds = [STAKING_POOL_SEED],
-        bump = staking_pool.bump,
-        constraint = staking_pool.is_active @ ErrorCode::PoolNotActive,
+        seeds = [PROPOSAL_SEED, &proposal_account.proposal_id.to_le_bytes()],
+        bump = proposal_account.bump,
     )]
-    pub staking_pool: Account<'info, StakingPool>,
+    pub proposal_account: Account<'info, ProposalAccount>,
 
     #[account(
         mut,
-        seeds = [USER_STAKE_SEED, staker.key().as_ref()],
-        bump = user_staking_account.bump,
-        constraint = user_staking_account.staker == staker.key() @ ErrorCode::UnauthorizedStaker,
+        seeds = [SPONSORSHIP_SEED, &proposal_account.proposal_id.to_le_bytes(), sponsorship.sponsor.as_ref()],
+        bump = sponsorship.bump,
+        close = sponsor,
     )]
-    pub user_staking_account: Account<'info, UserStakingAccount>,
+    pub sponsorship: Account<'info, ProposalSponsorship>,
+
+    #[account(mut, address = sponsorship.sponsor @ ErrorCode::InvalidSponsor)]
+    /// CHECK: Original payer of the sponsorship account, receives its rent
+    pub sponsor: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_SEED, sponsorship.sponsor.as_ref()],
+        bump = sponsor_governance_account.bump,
+    )]
+    pub sponsor_governance_account: Account<'info, GovernanceAccount>,
 
-    #[account(seeds = [GOVERNANCE_SEED, staker.key().as_ref()], bump = governance_account.bump)]
-    pub governance_account: Option<Account<'info, GovernanceAccount>>,
+    #[account(seeds = [STAKING_POOL_SEED], bump = staking_pool.bump)]
+    pub staking_pool: Account<'info, StakingPool>,
+
+    #[account(seeds = [GOVERNANCE_CONFIG_SEED], bump = governance_config.bump)]
+    pub governance_config: Account<'info, GovernanceConfig>,
 
     #[account(seeds = [PROGRAM_AUTHORITY_SEED], bump = staking_pool.authority_bump)]
     /// CHECK: Program authority PDA
     pub program_authority: UncheckedAccount<'info>,
 
-    #[account(mut, seeds = [STAKING_POOL_ESCROW_SEED, staking_pool.key().as_ref()], bump)]
-    pub escrow_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(mut, seeds = [PROPOSAL_ESCROW_SEED], bump)]
+    pub deposit_escrow_account: InterfaceAccount<'info, TokenAccount>,
 
-    #[account(
-        mut,
-        associated_token::mint = token_mint,
-        associated_token::authority = staker,
-        associated_token::token_program = token_program
-    )]
-    pub staker_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(mut, constraint = sponsor_token_account.owner == sponsorship.sponsor @ ErrorCode::InvalidSponsor)]
+    pub sponsor_token_account: InterfaceAccount<'info, TokenAccount>,
 
     #[account(constraint = token_mint.to_account_info().owner == &spl_token_2022::ID @ ErrorCode::InvalidTokenProgram)]
     pub token_mint: InterfaceAccount<'info, Mint>,
 
     pub token_program: Interface<'info, TokenInterface>,
+
+    // OPTIONAL - only needed when a vetoed deposit is sent to the treasury
+    #[account(mut, seeds = [TREASURY_SEED, staking_pool.key().as_ref()], bump)]
+    pub treasury_account: Option<InterfaceAccount<'info, TokenAccount>>,
 }
 
 #[derive(Accounts)]
-pub struct InitializeGovernanceAccount<'info> {
-    #[account(mut)]
-    pub staker: Signer<'info>,
+pub struct ActivateProposal<'info> {
+    pub proposer: Signer<'info>,
 
-    #[account(init, payer = staker, space = 8 + 69, seeds = [GOVERNANCE_SEED, staker.key().as_ref()], bump)]
-    pub governance_account: Account<'info, GovernanceAccount>,
+    #[account(
+        mut,
+        seeds = [PROPOSAL_SEED, &proposal_account.proposal_id.to_le_bytes()],
+        bump = proposal_account.bump,
+        constraint = proposal_account.proposer == proposer.key() @ ErrorCode::NotProposer,
+    )]
+    pub proposal_account: Account<'info, ProposalAccount>,
 
-    pub system_program: Program<'info, System>,
+    #[account(seeds = [STAKING_POOL_SEED], bump = staking_pool.bump)]
+    pub staking_pool: Account<'info, StakingPool>,
+
+    #[account(seeds = [GOVERNANCE_CONFIG_SEED], bump = governance_config.bump)]
+    pub governance_config: Account<'info, GovernanceConfig>,
 }
 
 #[derive(Accounts)]
-pub struct InitializeGovernanceConfig<'info> {
+pub struct CloseProposal<'info> {
     #[account(mut)]
-    pub authority: Signer<'info>,
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PROPOSAL_SEED, &proposal_account.proposal_id.to_le_bytes()],
+        bump = proposal_account.bump,
+        close = proposer,
+    )]
+    pub proposal_account: Account<'info, ProposalAccount>,
+
+    #[account(mut, address = proposal_account.proposer @ ErrorCode::InvalidProposerAccount)]
+    /// CHECK: Original payer of the proposal account, receives its rent
+    pub proposer: UncheckedAccount<'info>,
+
+    #[account(seeds = [GOVERNANCE_CONFIG_SEED], bump = governance_config.bump)]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    // OPTIONAL - required for custom instruction proposals, closed alongside them
+    #[account(
+        mut,
+        seeds = [PROPOSAL_INSTRUCTIONS_SEED, &proposal_account.proposal_id.to_le_bytes(), proposal_account.proposer.as_ref()],
+        bump = proposal_instructions.bump,
+        close = proposer,
+    )]
+    pub proposal_instructions: Option<Account<'info, ProposalInstructions>>,
 
+    // OPTIONAL - only needed to keep an on-chain summary of the closed proposal
     #[account(
         init,
-        payer = authority,
-        space = 8 + 121,
-        seeds = [GOVERNANCE_CONFIG_SEED],
-        bump
+        payer = caller,
+        space = 8 + 143,
+        seeds = [PROPOSAL_SUMMARY_SEED, &proposal_account.proposal_id.to_le_bytes()],
+        bump,
     )]
-    pub governance_config: Account<'info, GovernanceConfig>,
+    pub proposal_summary: Option<Account<'info, ProposalSummary>>,
 
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct CalculateVotingPower<'info> {
-    pub staker: Signer<'info>,
+pub struct CloseVoteRecord<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
 
     #[account(
-        seeds = [USER_STAKE_SEED, staker.key().as_ref()],
-        bump = user_staking_account.bump,
-        constraint = user_staking_account.staker == staker.key() @ ErrorCode::UnauthorizedStaker,
+        mut,
+        seeds = [VOTE_SEED, vote_record.proposal_id.to_le_bytes().as_ref(), voter.key().as_ref()],
+        bump = vote_record.bump,
+        close = voter,
     )]
-    pub user_staking_account: Account<'info, UserStakingAccount>,
+    pub vote_record: Account<'info, VoteRecord>,
 
-    #[account(mut, seeds = [GOVERNANCE_SEED, staker.key().as_ref()], bump = governance_account.bump)]
-    pub governance_account: Account<'info, GovernanceAccount>,
+    #[account(seeds = [PROPOSAL_SEED, vote_record.proposal_id.to_le_bytes().as_ref()], bump)]
+    /// CHECK: Proposal PDA for this vote; may already be closed, deserialized in the handler
+    pub proposal_account: UncheckedAccount<'info>,
+
+    #[account(seeds = [GOVERNANCE_CONFIG_SEED], bump = governance_config.bump)]
+    pub governance_config: Account<'info, GovernanceConfig>,
 }
 
 #[derive(Accounts)]
-pub struct InitializeProposalEscrow<'info> {
+pub struct ExpireProposal<'info> {
     #[account(mut)]
-    pub admin: Signer<'info>,
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PROPOSAL_SEED, &proposal_account.proposal_id.to_le_bytes()],
+        bump = proposal_account.bump,
+    )]
+    pub proposal_account: Account<'info, ProposalAccount>,
 
     #[account(seeds = [STAKING_POOL_SEED], bump = staking_pool.bump)]
     pub staking_pool: Account<'info, StakingPool>,
 
+    #[account(seeds = [GOVERNANCE_CONFIG_SEED], bump = governance_config.bump)]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
     #[account(seeds = [PROGRAM_AUTHORITY_SEED], bump = staking_pool.authority_bump)]
     /// CHECK: Program authority PDA
     pub program_authority: UncheckedAccount<'info>,
 
-    #[account(
-        init,
-        payer = admin,
-        token::mint = token_mint,
-        token::authority = program_authority,
-        seeds = [PROPOSAL_ESCROW_SEED],
-        bump
-    )]
-    pub proposal_escrow: InterfaceAccount<'info, TokenAccount>,
+    #[account(mut, seeds = [PROPOSAL_ESCROW_SEED], bump)]
+    pub deposit_escrow_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(mut, constraint = proposer_token_account.owner == proposal_account.proposer @ ErrorCode::InvalidProposerAccount)]
+    pub proposer_token_account: InterfaceAccount<'info, TokenAccount>,
 
     #[account(constraint = token_mint.to_account_info().owner == &spl_token_2022::ID @ ErrorCode::InvalidTokenProgram)]
     pub token_mint: InterfaceAccount<'info, Mint>,
 
-    pub system_program: Program<'info, System>,
     pub token_program: Interface<'info, TokenInterface>,
 }
 
 #[derive(Accounts)]
-#[instruction(proposal_id: u64)]
-pub struct CreateProposal<'info> {
-    #[account(mut)]
-    pub proposer: Signer<'info>,
-
-    #[account(
-        seeds = [USER_STAKE_SEED, proposer.key().as_ref()],
-        bump = proposer_staking_account.bump,
-        constraint = proposer_staking_account.staker == proposer.key() @ ErrorCode::UnauthorizedStaker,
-    )]
-    pub proposer_staking_account: Account<'info, UserStakingAccount>,
+pub struct RefreshVoteLock<'info> {
+    #[account(seeds = [PROPOSAL_SEED, proposal_account.proposal_id.to_le_bytes().as_ref()], bump = proposal_account.bump)]
+    pub proposal_account: Account<'info, ProposalAccount>,
 
     #[account(
-        seeds = [GOVERNANCE_SEED, proposer.key().as_ref()],
-        bump = proposer_governance_account.bump,
-        constraint = proposer_governance_account.staker == proposer.key() @ ErrorCode::UnauthorizedStaker,
+        seeds = [VOTE_SEED, proposal_account.proposal_id.to_le_bytes().as_ref(), vote_record.voter.as_ref()],
+        bump = vote_record.bump,
     )]
-    pub proposer_governance_account: Account<'info, GovernanceAccount>,
-
-    #[account(init, payer = proposer, space = 8 + 2700, seeds = [PROPOSAL_SEED, proposal_id.to_le_bytes().as_ref()], bump)]
-    pub proposal_account: Account<'info, ProposalAccount>,
-
-    #[account(seeds = [STAKING_POOL_SEED], bump = staking_pool.bump)]
-    pub staking_pool: Account<'info, StakingPool>,
-
-    #[account(seeds = [PROGRAM_AUTHORITY_SEED], bump = staking_pool.authority_bump)]
-    /// CHECK: Program authority PDA
-    pub program_authority: UncheckedAccount<'info>,
+    pub vote_record: Account<'info, VoteRecord>,
 
     #[account(
         mut,
-        associated_token::mint = deposit_token_mint,
-        associated_token::authority = proposer,
-        associated_token::token_program = token_program,
+        seeds = [GOVERNANCE_SEED, vote_record.voter.as_ref()],
+        bump = governance_account.bump,
     )]
-    pub proposer_token_account: InterfaceAccount<'info, TokenAccount>,
-
-    #[account(mut, seeds = [PROPOSAL_ESCROW_SEED], bump)]
-    pub deposit_escrow_account: InterfaceAccount<'info, TokenAccount>,
-
-    #[account(constraint = deposit_token_mint.to_account_info().owner == &spl_token_2022::ID @ ErrorCode::InvalidTokenProgram)]
-    pub deposit_token_mint: InterfaceAccount<'info, Mint>,
-
-    pub system_program: Program<'info, System>,
-    pub token_program: Interface<'info, TokenInterface>,
+    pub governance_account: Account<'info, GovernanceAccount>,
 }
 
 #[derive(Accounts)]
-#[instruction(vote_choice: VoteChoice)]
-pub struct CastVote<'info> {
+pub struct CommitVote<'info> {
     #[account(mut)]
     pub voter: Signer<'info>,
 
@@ -1188,7 +4706,7 @@ pub struct CastVote<'info> {
     #[account(
         init,
         payer = voter,
-        space = 8 + 82,
+        space = 8 + 91,
         seeds = [VOTE_SEED, proposal_account.proposal_id.to_le_bytes().as_ref(), voter.key().as_ref()],
         bump
     )]
@@ -1198,44 +4716,71 @@ pub struct CastVote<'info> {
 }
 
 #[derive(Accounts)]
-pub struct FinalizeProposal<'info> {
-    #[account(mut)]
-    pub finalizer: Signer<'info>,
+pub struct RevealVote<'info> {
+    pub voter: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_SEED, voter.key().as_ref()],
+        bump = governance_account.bump,
+        constraint = governance_account.staker == voter.key() @ ErrorCode::UnauthorizedStaker,
+    )]
+    pub governance_account: Account<'info, GovernanceAccount>,
 
     #[account(mut, seeds = [PROPOSAL_SEED, proposal_account.proposal_id.to_le_bytes().as_ref()], bump = proposal_account.bump)]
     pub proposal_account: Account<'info, ProposalAccount>,
 
-    #[account(seeds = [STAKING_POOL_SEED], bump = staking_pool.bump)]
-    pub staking_pool: Account<'info, StakingPool>,
+    #[account(
+        mut,
+        seeds = [VOTE_SEED, proposal_account.proposal_id.to_le_bytes().as_ref(), voter.key().as_ref()],
+        bump = vote_record.bump,
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+}
 
-    #[account(seeds = [PROGRAM_AUTHORITY_SEED], bump = staking_pool.authority_bump)]
-    /// CHECK: Program authority PDA
-    pub program_authority: UncheckedAccount<'info>,
+#[derive(Accounts)]
+pub struct PenalizeUnrevealedVote<'info> {
+    pub caller: Signer<'info>,
 
-    #[account(mut, seeds = [PROPOSAL_ESCROW_SEED], bump)]
-    pub deposit_escrow_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(seeds = [PROPOSAL_SEED, proposal_account.proposal_id.to_le_bytes().as_ref()], bump = proposal_account.bump)]
+    pub proposal_account: Account<'info, ProposalAccount>,
 
-    #[account(mut, constraint = proposer_token_account.owner == proposal_account.proposer @ ErrorCode::InvalidProposerAccount)]
-    pub proposer_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        seeds = [VOTE_SEED, proposal_account.proposal_id.to_le_bytes().as_ref(), vote_record.voter.as_ref()],
+        bump = vote_record.bump,
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
 
-    #[account(constraint = token_mint.to_account_info().owner == &spl_token_2022::ID @ ErrorCode::InvalidTokenProgram)]
-    pub token_mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_SEED, vote_record.voter.as_ref()],
+        bump = governance_account.bump,
+    )]
+    pub governance_account: Account<'info, GovernanceAccount>,
 
-    pub token_program: Interface<'info, TokenInterface>,
+    #[account(seeds = [GOVERNANCE_CONFIG_SEED], bump = governance_config.bump)]
+    pub governance_config: Account<'info, GovernanceConfig>,
 }
 
 #[derive(Accounts)]
-pub struct ExecuteProposal<'info> {
-    #[account(mut)]
-    pub executor: Signer<'info>,
+pub struct CancelDraft<'info> {
+    pub proposer: Signer<'info>,
 
     #[account(
         mut,
         seeds = [PROPOSAL_SEED, &proposal_account.proposal_id.to_le_bytes()],
         bump = proposal_account.bump,
+        constraint = proposal_account.proposer == proposer.key() @ ErrorCode::NotProposer,
     )]
     pub proposal_account: Account<'info, ProposalAccount>,
 
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_SEED, proposer.key().as_ref()],
+        bump = proposer_governance_account.bump,
+    )]
+    pub proposer_governance_account: Account<'info, GovernanceAccount>,
+
     #[account(seeds = [STAKING_POOL_SEED], bump = staking_pool.bump)]
     pub staking_pool: Account<'info, StakingPool>,
 
@@ -1243,42 +4788,28 @@ pub struct ExecuteProposal<'info> {
     /// CHECK: Program authority PDA
     pub program_authority: UncheckedAccount<'info>,
 
-    #[account(
-        mut,
-        seeds = [PROPOSAL_ESCROW_SEED],
-        bump,
-    )]
+    #[account(mut, seeds = [PROPOSAL_ESCROW_SEED], bump)]
     pub deposit_escrow_account: InterfaceAccount<'info, TokenAccount>,
 
-    #[account(mut)]
+    #[account(mut, constraint = proposer_token_account.owner == proposer.key() @ ErrorCode::InvalidProposerAccount)]
     pub proposer_token_account: InterfaceAccount<'info, TokenAccount>,
 
-    pub deposit_token_mint: InterfaceAccount<'info, Mint>,
-
-    pub token_program_for_deposit: Interface<'info, TokenInterface>,
-
-    // REQUIRED - governance config is needed for parameter updates
-    #[account(
-        mut,
-        seeds = [GOVERNANCE_CONFIG_SEED],
-        bump = governance_config.bump,
-    )]
-    pub governance_config: Account<'info, GovernanceConfig>,
+    #[account(constraint = token_mint.to_account_info().owner == &spl_token_2022::ID @ ErrorCode::InvalidTokenProgram)]
+    pub token_mint: InterfaceAccount<'info, Mint>,
 
-    // OPTIONAL - only needed for treasury transfers
-    #[account(mut)]
-    /// CHECK: Optional treasury account for treasury transfer proposals
-    pub treasury_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
 
+#[derive(Accounts)]
+pub struct MigrateGovernanceAccount<'info> {
     #[account(mut)]
-    /// CHECK: Optional recipient account for treasury transfer proposals
-    pub recipient_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    pub staker: Signer<'info>,
 
-    /// CHECK: Optional token mint for treasury transfers
-    pub token_mint: Option<InterfaceAccount<'info, Mint>>,
+    #[account(mut, owner = crate::ID, seeds = [GOVERNANCE_SEED, staker.key().as_ref()], bump)]
+    /// CHECK: Staker's governance PDA in a possibly outdated layout, validated in the handler
+    pub governance_account: UncheckedAccount<'info>,
 
-    /// CHECK: Optional token program for treasury transfers
-    pub token_program: Option<Interface<'info, TokenInterface>>,
+    pub system_program: Program<'info, System>,
 }
 // ============================================================================
 // ERROR CODES
@@ -1310,15 +4841,13 @@ pub enum ErrorCode {
     StalePrice,
     #[msg("Tokens are currently locked for governance participation")]
     TokensLockedForGovernance,
-    #[msg(
-        "Insufficient stake amount to create proposal - minimum 100 ZSNIPE tokens must be staked"
-    )]
+    #[msg("Insufficient stake amount to create proposal - below the required proposal stake")]
     InsufficientStakeToPropose,
-    #[msg("Insufficient stake duration to create proposal - minimum 30 days required")]
+    #[msg("Insufficient stake duration to create proposal - below the configured minimum")]
     InsufficientStakeDurationToPropose,
-    #[msg("Insufficient deposit - 1000 ZSNIPE tokens required to create a proposal")]
+    #[msg("Insufficient deposit to create a proposal")]
     InsufficientDepositToPropose,
-    #[msg("Maximum Active proposals reached - You have 3 active proposals already")]
+    #[msg("Maximum active proposals reached for this proposer")]
     MaxActiveProposalsReached,
     #[msg("Proposal title too long - Max 100 Characters")]
     ProposalTitleTooLong,
@@ -1326,19 +4855,19 @@ pub enum ErrorCode {
     ProposalDescriptionTooLong,
     #[msg("Execution data too large - max 500 bytes")]
     ExecutionDataTooLarge,
-    #[msg("Invalid voting period - must be 3, 7, or 14 days")]
+    #[msg("Invalid voting period - must be within the configured min/max voting period hours")]
     InvalidVotingPeriod,
     #[msg("Proposal is not active - cannot vote")]
     ProposalNotActive,
     #[msg("Voting period has ended")]
     VotingPeriodEnded,
-    #[msg("Insufficient stake duration to vote - minimum 30 days required")]
+    #[msg("Insufficient stake duration to vote - below the minimum staking period")]
     InsufficientStakeDurationToVote,
     #[msg("Voting power not calculated - call calculate_voting_power first")]
     VotingPowerNotCalculated,
     #[msg("Already voted on this proposal - vote changes not allowed")]
     AlreadyVoted,
-    #[msg("Voting period has not ended yet")]
+    #[msg("Voting period has not ended yet and the outcome is not yet decided")]
     VotingPeriodNotEnded,
     #[msg("Proposal has already been finalized")]
     ProposalAlreadyFinalized,
@@ -1352,6 +4881,12 @@ pub enum ErrorCode {
     ProposalAlreadyExecuted,
     #[msg("Invalid execution data format")]
     InvalidExecutionData,
+    #[msg("Unsupported execution payload version")]
+    UnsupportedExecutionPayloadVersion,
+    #[msg("Execution payload does not match proposal type")]
+    ExecutionPayloadTypeMismatch,
+    #[msg("Recipient token account does not belong to the proposal recipient")]
+    InvalidRecipientAccount,
     #[msg("Missing treasury account for treasury transfer")]
     MissingTreasuryAccount,
     #[msg("Missing recipient account for treasury transfer")]
@@ -1372,4 +4907,364 @@ pub enum ErrorCode {
     GovernanceConfigNotInitialized,
     #[msg("Invalid governance config")]
     InvalidGovernanceConfig,
+    #[msg("Invalid instruction count - between 1 and 8 instructions required")]
+    InvalidInstructionCount,
+    #[msg("Too many accounts in proposal instruction - max 16")]
+    TooManyInstructionAccounts,
+    #[msg("Proposal instruction data too large - max 512 bytes")]
+    InstructionDataTooLarge,
+    #[msg("Only the program authority may sign proposal instructions")]
+    InvalidInstructionSigner,
+    #[msg("Proposal instructions may not reference staking or deposit escrows")]
+    ProtectedAccountInInstruction,
+    #[msg("Missing proposal instructions account for custom instruction proposal")]
+    MissingProposalInstructions,
+    #[msg("Proposal instructions do not belong to this proposer")]
+    InvalidProposalInstructions,
+    #[msg("Invalid proposal type for this operation")]
+    InvalidProposalType,
+    #[msg("Emergency override not authorized")]
+    EmergencyNotAuthorized,
+    #[msg("Too many batch updates - maximum 10 allowed")]
+    TooManyBatchUpdates,
+    #[msg("Emergency mode is not active")]
+    EmergencyModeNotActive,
+    #[msg("Emergency mode cooldown has not elapsed")]
+    EmergencyCooldownActive,
+    #[msg("Parameter bounds validation failed")]
+    ParameterBoundsValidationFailed,
+    #[msg("Parameter update does not match the proposal's execution data")]
+    ParameterUpdateMismatch,
+    #[msg("Missing deposit refund accounts for governance parameter update")]
+    MissingDepositAccounts,
+    #[msg("Proposal instructions must be executed in order")]
+    InstructionExecutedOutOfOrder,
+    #[msg("Invalid proposal instruction index")]
+    InvalidInstructionIndex,
+    #[msg("Not all proposal instructions have been executed yet")]
+    ProposalInstructionsPending,
+    #[msg("Proposal cooldown active - wait before creating another proposal")]
+    ProposalCooldownActive,
+    #[msg("Execution grace period has passed - proposal can only be expired")]
+    ProposalExecutionExpired,
+    #[msg("Execution grace period has not ended yet")]
+    ExecutionGracePeriodActive,
+    #[msg("Only text and treasury transfer proposals can be optimistic")]
+    OptimisticProposalNotAllowed,
+    #[msg("Treasury amount exceeds the optimistic proposal cap")]
+    OptimisticTreasuryAmountTooLarge,
+    #[msg("Invalid metadata URI - must be non-empty and max 200 characters")]
+    InvalidMetadataUri,
+    #[msg("Invalid content hash for off-chain proposal metadata")]
+    InvalidContentHash,
+    #[msg("Proposal is not settled yet - cannot reclaim rent")]
+    ProposalNotSettled,
+    #[msg("Proposal archival period or vote lock has not passed yet")]
+    ArchivalPeriodActive,
+    #[msg("Proposal has not been finalized yet")]
+    ProposalNotFinalized,
+    #[msg("Proposal is not a draft")]
+    ProposalNotDraft,
+    #[msg("Only the proposer can modify this proposal")]
+    NotProposer,
+    #[msg("Invalid sponsor for this proposal")]
+    InvalidSponsor,
+    #[msg("Maximum number of sponsors reached for this proposal")]
+    TooManySponsors,
+    #[msg("Sponsorship exceeds the stake still required by the proposal")]
+    SponsorshipExceedsRequirement,
+    #[msg("All sponsorships must be settled before closing the proposal")]
+    SponsorshipsNotSettled,
+    #[msg("Invalid vote record accounts - expected (vote_record, voter) pairs for this proposal")]
+    InvalidVoteRecordAccounts,
+    #[msg("Proposal uses private voting - commit and reveal the vote instead")]
+    PrivateVotingRequired,
+    #[msg("Proposal does not use private voting")]
+    ProposalNotPrivate,
+    #[msg("Emergency proposals can't use private voting")]
+    PrivateVotingNotAllowed,
+    #[msg("Reveal period is still active")]
+    RevealPeriodActive,
+    #[msg("Reveal period has ended")]
+    RevealPeriodEnded,
+    #[msg("Vote has already been revealed")]
+    VoteAlreadyRevealed,
+    #[msg("Revealed vote does not match the commitment")]
+    InvalidVoteReveal,
+    #[msg("Penalty for unrevealed votes is disabled")]
+    UnrevealedVotePenaltyDisabled,
+    #[msg("Proposal id must be the next id allocated by the governance config")]
+    InvalidProposalId,
+    #[msg("Governance account already uses the current layout")]
+    GovernanceAccountUpToDate,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TOTAL_VOTING_POWER: u64 = 1_000;
+    const NOW: i64 = 1_700_000_000;
+
+    fn governance_config() -> GovernanceConfig {
+        GovernanceConfig {
+            authority: Pubkey::default(),
+            staking_pool: Pubkey::default(),
+            parameter_bounds: ParameterBounds::default(),
+            time_multipliers: TimeMultipliers::default(),
+            proposal_settings: ProposalSettings::default(),
+            execution_settings: ExecutionSettings::default(),
+            emergency_settings: EmergencySettings::default(),
+            proposal_type_rules: ProposalTypeRules::defaults(),
+            version: 1,
+            created_at: 0,
+            last_updated: 0,
+            bump: 0,
+            next_proposal_id: 0,
+        }
+    }
+
+    fn activate_emergency(config: &mut GovernanceConfig) {
+        config.emergency_settings.emergency_override_enabled = true;
+        config.emergency_settings.emergency_activated_at = NOW - SECONDS_PER_HOUR;
+    }
+
+    fn proposal(proposal_type: ProposalType, yes: u64, no: u64, abstain: u64) -> ProposalAccount {
+        ProposalAccount {
+            proposal_id: 0,
+            proposer: Pubkey::default(),
+            title: String::new(),
+            description: String::new(),
+            proposal_type,
+            status: ProposalStatus::Active,
+            execution_data: Vec::new(),
+            voting_period_hours: 72,
+            created_at: NOW - 72 * SECONDS_PER_HOUR,
+            voting_ends_at: NOW,
+            finalized_at: 0,
+            executed_at: 0,
+            timelock_end: 0,
+            yes_votes: yes,
+            no_votes: no,
+            abstain_votes: abstain,
+            total_voters: 0,
+            deposit_amount: 0,
+            deposit_refunded: false,
+            bump: 0,
+            veto_votes: 0,
+            is_optimistic: false,
+            metadata_uri: String::new(),
+            content_hash: [0; 32],
+            required_stake: 0,
+            backing_stake: 0,
+            sponsor_count: 0,
+            abstain_policy: AbstainPolicy::QuorumOnly,
+            last_vote_at: NOW,
+            vote_extensions: 0,
+            is_private: false,
+            reveal_ends_at: 0,
+            voting_power_snapshot: TOTAL_VOTING_POWER,
+            proposer_pledge: 0,
+            reserved: [0; 3],
+        }
+    }
+
+    fn tally(proposal: &ProposalAccount, config: &GovernanceConfig) -> (ProposalStatus, bool) {
+        tally_outcome(proposal, config, TOTAL_VOTING_POWER, NOW).unwrap()
+    }
+
+    #[test]
+    fn tally_passes_with_quorum_and_threshold() {
+        let config = governance_config();
+        let proposal = proposal(ProposalType::Text, 150, 50, 0);
+
+        assert_eq!(tally(&proposal, &config), (ProposalStatus::Passed, true));
+    }
+
+    #[test]
+    fn tally_fails_without_quorum() {
+        let config = governance_config();
+        let proposal = proposal(ProposalType::Text, 199, 0, 0);
+
+        assert_eq!(tally(&proposal, &config), (ProposalStatus::Failed, false));
+    }
+
+    #[test]
+    fn tally_fails_on_even_split() {
+        let config = governance_config();
+        let proposal = proposal(ProposalType::Text, 150, 150, 0);
+
+        assert_eq!(tally(&proposal, &config), (ProposalStatus::Failed, true));
+    }
+
+    #[test]
+    fn tally_vetoes_once_veto_share_exceeds_threshold() {
+        let config = governance_config();
+        let mut proposal = proposal(ProposalType::Text, 100, 120, 0);
+        proposal.veto_votes = 100;
+
+        assert_eq!(tally(&proposal, &config), (ProposalStatus::Vetoed, true));
+    }
+
+    #[test]
+    fn tally_passes_unopposed_optimistic_proposal_without_quorum() {
+        let config = governance_config();
+        let mut proposal = proposal(ProposalType::Text, 0, 100, 0);
+        proposal.is_optimistic = true;
+
+        assert_eq!(tally(&proposal, &config), (ProposalStatus::Passed, false));
+    }
+
+    #[test]
+    fn tally_fails_objected_optimistic_proposal_even_with_yes_majority() {
+        let config = governance_config();
+        let mut proposal = proposal(ProposalType::Text, 500, 101, 0);
+        proposal.is_optimistic = true;
+
+        assert_eq!(tally(&proposal, &config), (ProposalStatus::Failed, true));
+    }
+
+    #[test]
+    fn tally_applies_emergency_quorum_reduction() {
+        let mut config = governance_config();
+        let proposal = proposal(ProposalType::Emergency, 150, 0, 0);
+
+        assert_eq!(tally(&proposal, &config), (ProposalStatus::Failed, false));
+
+        activate_emergency(&mut config);
+        assert_eq!(tally(&proposal, &config), (ProposalStatus::Passed, true));
+    }
+
+    #[test]
+    fn outcome_decided_when_remaining_votes_cannot_flip_it() {
+        let config = governance_config();
+        let rules = config.rules_for(ProposalType::Text);
+        let proposal = proposal(ProposalType::Text, 600, 0, 0);
+
+        assert!(is_outcome_decided(&proposal, 100, &config.proposal_settings, &rules).unwrap());
+    }
+
+    #[test]
+    fn outcome_undecided_while_remaining_votes_can_flip_it() {
+        let config = governance_config();
+        let rules = config.rules_for(ProposalType::Text);
+        let proposal = proposal(ProposalType::Text, 100, 0, 0);
+
+        assert!(!is_outcome_decided(&proposal, 500, &config.proposal_settings, &rules).unwrap());
+    }
+
+    #[test]
+    fn outcome_decided_once_veto_is_locked_in() {
+        let config = governance_config();
+        let rules = config.rules_for(ProposalType::Text);
+        let mut proposal = proposal(ProposalType::Text, 0, 400, 0);
+        proposal.veto_votes = 400;
+
+        assert!(is_outcome_decided(&proposal, 100, &config.proposal_settings, &rules).unwrap());
+    }
+
+    #[test]
+    fn effective_quorum_is_unchanged_outside_an_emergency() {
+        let config = governance_config();
+
+        assert_eq!(config.get_effective_quorum(3000, NOW), 3000);
+    }
+
+    #[test]
+    fn effective_quorum_is_reduced_during_an_emergency() {
+        let mut config = governance_config();
+        activate_emergency(&mut config);
+
+        assert_eq!(config.get_effective_quorum(3000, NOW), 2000);
+    }
+
+    #[test]
+    fn effective_quorum_never_drops_below_the_minimum() {
+        let mut config = governance_config();
+        activate_emergency(&mut config);
+        config.emergency_settings.emergency_quorum_reduction = 1500;
+
+        assert_eq!(
+            config.get_effective_quorum(2000, NOW),
+            config.parameter_bounds.min_quorum_percentage
+        );
+    }
+
+    #[test]
+    fn effective_quorum_reduction_expires_after_cooldown() {
+        let mut config = governance_config();
+        activate_emergency(&mut config);
+        let cooldown = config.emergency_settings.emergency_cooldown_hours as i64 * SECONDS_PER_HOUR;
+
+        assert_eq!(config.get_effective_quorum(3000, NOW + cooldown), 3000);
+    }
+
+    #[test]
+    fn stake_requirement_uses_base_stake_without_history() {
+        let config = governance_config();
+
+        assert_eq!(
+            config.calculate_proposal_stake_requirement(0, 0).unwrap(),
+            config.proposal_settings.base_proposal_stake
+        );
+    }
+
+    #[test]
+    fn stake_requirement_escalates_with_proposer_history() {
+        let config = governance_config();
+        let base_stake = config.proposal_settings.base_proposal_stake;
+
+        // 1.5% more per failure at the default escalation factor of 150
+        assert_eq!(
+            config.calculate_proposal_stake_requirement(20, 0).unwrap(),
+            base_stake * 130 / 100
+        );
+        // Capped at the 500% maximum multiplier
+        assert_eq!(
+            config.calculate_proposal_stake_requirement(1_000, 0).unwrap(),
+            base_stake * 5
+        );
+    }
+
+    #[test]
+    fn stake_requirement_escalation_does_not_wrap_for_long_histories() {
+        let config = governance_config();
+        let base_stake = config.proposal_settings.base_proposal_stake;
+
+        for proposer_history in [u16::MAX as u32 + 1, u32::MAX] {
+            assert_eq!(
+                config.calculate_proposal_stake_requirement(proposer_history, 0).unwrap(),
+                base_stake * 5
+            );
+        }
+    }
+
+    #[test]
+    fn stake_requirement_in_share_mode_follows_total_voting_power() {
+        let mut config = governance_config();
+        config.proposal_settings.proposal_threshold_mode = ProposalThresholdMode::VotingPowerShare;
+
+        // 0.5% of 1,000,000 tokens of voting power
+        assert_eq!(
+            config.calculate_proposal_stake_requirement(0, 1_000_000).unwrap(),
+            5_000 * 1_000_000
+        );
+        // Not raised to the absolute minimum stake for a small pool
+        assert_eq!(
+            config.calculate_proposal_stake_requirement(0, 100).unwrap(),
+            500_000
+        );
+    }
+
+    #[test]
+    fn stake_requirement_in_share_mode_is_bounded_by_total_voting_power() {
+        let mut config = governance_config();
+        config.proposal_settings.proposal_threshold_mode = ProposalThresholdMode::VotingPowerShare;
+        config.proposal_settings.proposal_threshold_percentage = 5000;
+
+        assert_eq!(
+            config.calculate_proposal_stake_requirement(1_000, 1_000).unwrap(),
+            1_000 * 1_000_000
+        );
+    }
 }
@@ -156,7 +156,7 @@ const options: swaggerJsdoc.Options = {
                 proposer: { type: 'string' },
                 proposalType: { type: 'object' },
                 status: { type: 'object' },
-                votingPeriodDays: { type: 'number' },
+                votingPeriodHours: { type: 'number' },
                 createdAt: { type: 'number' },
                 votingEndsAt: { type: 'number' },
                 finalizedAt: { type: 'number' },
@@ -177,7 +177,8 @@ export async function createProposalTransaction(req: Request, res: Response) {
       description,
       proposalType = 0,
       executionData = [],
-      votingPeriod = 7
+      votingPeriod = 72,
+      optimistic = false
     } = req.body;
 
     // Validation
@@ -216,7 +217,8 @@ export async function createProposalTransaction(req: Request, res: Response) {
       description.trim(),
       proposalType,
       executionData,
-      votingPeriod
+      votingPeriod,
+      Boolean(optimistic)
     );
 
     res.status(200).json({
@@ -259,16 +261,16 @@ export async function createVoteTransaction(req: Request, res: Response) {
     if (voteChoice === undefined || voteChoice === null) {
       return res.status(400).json({ 
         success: false, 
-        error: 'voteChoice is required (0=Yes, 1=No, 2=Abstain)' 
+        error: 'voteChoice is required (0=Yes, 1=No, 2=Abstain, 3=NoWithVeto)' 
       });
     }
 
     // Validate vote choice
     const voteChoiceNum = Number(voteChoice);
-    if (![0, 1, 2].includes(voteChoiceNum)) {
+    if (![0, 1, 2, 3].includes(voteChoiceNum)) {
       return res.status(400).json({ 
         success: false, 
-        error: 'Invalid voteChoice. Must be 0 (Yes), 1 (No), or 2 (Abstain)' 
+        error: 'Invalid voteChoice. Must be 0 (Yes), 1 (No), 2 (Abstain), or 3 (NoWithVeto)' 
       });
     }
 
@@ -687,11 +689,12 @@ export async function buildTreasuryExecutionDataControllerInterface(req: Request
 
 export async function buildParameterExecutionDataController(req: Request, res: Response) {
   try {
-    const { parameterId, newValue } = req.body || {};
-    if (parameterId === undefined || newValue === undefined) {
-      return res.status(400).json({ success: false, error: 'parameterId and newValue are required' });
+    // update: { type, ...fields }, e.g. { type: "quorumThreshold", percentage: 2500 } (basis points)
+    const { update } = req.body || {};
+    if (!update || typeof update !== 'object' || !update.type) {
+      return res.status(400).json({ success: false, error: 'update object with a type is required' });
     }
-    const data = buildParameterUpdateExecutionData(Number(parameterId), Number(newValue));
+    const data = buildParameterUpdateExecutionData(update);
     res.status(200).json({ success: true, data: { executionData: data, length: data.length } });
   } catch (error: any) {
     res.status(400).json({ success: false, error: error?.message || 'Failed to build parameter execution data' });
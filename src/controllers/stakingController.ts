@@ -345,7 +345,8 @@ export async function submitProposal(req: Request, res: Response) {
       description,
       proposalType = 0, // Default to Text proposal
       executionData = [],
-      votingPeriod = 7, // Default to 7 days
+      votingPeriod = 72, // Default to 72 hours
+      optimistic = false,
       walletNumber
     } = req.body;
 
@@ -385,7 +386,8 @@ export async function submitProposal(req: Request, res: Response) {
       proposalType,
       executionData,
       votingPeriod,
-      userKeypair
+      userKeypair,
+      Boolean(optimistic)
     );
 
     res.status(200).json({
@@ -509,16 +511,16 @@ export async function submitVote(req: Request, res: Response) {
     if (voteChoice === undefined || voteChoice === null) {
       return res.status(400).json({ 
         success: false, 
-        error: 'voteChoice is required (0=Yes, 1=No, 2=Abstain)' 
+        error: 'voteChoice is required (0=Yes, 1=No, 2=Abstain, 3=NoWithVeto)' 
       });
     }
 
         // Validate vote choice
         const voteChoiceNum = Number(voteChoice);
-        if (![0, 1, 2].includes(voteChoiceNum)) {
+        if (![0, 1, 2, 3].includes(voteChoiceNum)) {
           return res.status(400).json({ 
             success: false, 
-            error: 'Invalid voteChoice. Must be 0 (Yes), 1 (No), or 2 (Abstain)' 
+            error: 'Invalid voteChoice. Must be 0 (Yes), 1 (No), 2 (Abstain), or 3 (NoWithVeto)' 
           });
         }
        // Get user keypair
@@ -586,10 +588,10 @@ export async function bulkVote(req: Request, res: Response) {
     }
 
     const voteChoiceNum = Number(voteChoice);
-    if (![0, 1, 2].includes(voteChoiceNum)) {
+    if (![0, 1, 2, 3].includes(voteChoiceNum)) {
       return res.status(400).json({ 
         success: false, 
-        error: 'Invalid voteChoice. Must be 0 (Yes), 1 (No), or 2 (Abstain)' 
+        error: 'Invalid voteChoice. Must be 0 (Yes), 1 (No), 2 (Abstain), or 3 (NoWithVeto)' 
       });
     }
 
@@ -1207,30 +1209,27 @@ export const buildTreasuryExecutionDataController = async (req: Request, res: Re
  */
 export const buildParameterExecutionDataController = async (req: Request, res: Response) => {
   try {
-    const { parameterId, newValue } = req.body;
+    // update: { type, ...fields }, e.g. { type: "quorumThreshold", percentage: 2500 } (basis points)
+    const { update } = req.body;
 
-    if (parameterId === undefined || newValue === undefined) {
+    if (!update || typeof update !== 'object' || !update.type) {
       return res.status(400).json({
         success: false,
-        error: 'parameterId and newValue are required',
+        error: 'update object with a type is required',
       });
     }
 
-    const executionData = buildParameterUpdateExecutionData(
-      Number(parameterId),
-      Number(newValue)
-    );
-
-    const paramNames = ['Quorum Percentage', 'Passing Threshold', 'Timelock Duration'];
+    const executionData = buildParameterUpdateExecutionData(update);
+    const decoded = decodeParameterUpdateExecutionData(executionData);
 
     return res.status(200).json({
       success: true,
       data: {
         executionData,
         length: executionData.length,
-        parameterId: Number(parameterId),
-        parameterName: paramNames[Number(parameterId)],
-        newValue: Number(newValue),
+        parameterId: decoded.parameterId,
+        parameterName: decoded.parameterName,
+        update: decoded.update,
       },
     });
   } catch (error: any) {
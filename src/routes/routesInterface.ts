@@ -252,8 +252,8 @@ router.post('/transactions/calculate-voting-power', createCalculateVotingPowerTr
  *                 default: []
  *               votingPeriod:
  *                 type: number
- *                 default: 7
- *                 description: Voting period in days
+ *                 default: 72
+ *                 description: Voting period in hours
  *     responses:
  *       200:
  *         description: Proposal transaction created
@@ -587,9 +587,9 @@ router.get('/data/proposals', listProposalsInterface);
  *                       proposalType:
  *                         type: string
  *                         description: Type of proposal
- *                       votingPeriodDays:
+ *                       votingPeriodHours:
  *                         type: number
- *                         description: Voting period in days
+ *                         description: Voting period in hours
  *                       createdAt:
  *                         type: number
  *                         description: Creation timestamp
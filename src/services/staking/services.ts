@@ -193,6 +193,8 @@ function getProposalTypeEnum(proposalType: number) {
     case 0: return { text: {} };
     case 1: return { treasuryTransfer: {} };
     case 2: return { parameterUpdate: {} };
+    case 3: return { customInstructions: {} };
+    case 4: return { emergency: {} };
     default: throw new Error(`Invalid proposal type: ${proposalType}`);
   }
 }
@@ -450,15 +452,6 @@ export async function unstakeTokens(amount: number, userKeypair?: Keypair) {
     throw error;
   }
 
-  // Check if governance account exists (optional)
-  let hasGovernanceAccount = false;
-  try {
-    await program.account.governanceAccount.fetch(governanceAccount);
-    hasGovernanceAccount = true;
-  } catch (error) {
-    // No governance account, which is fine
-  }
-
   try {
     const accounts: any = {
       staker: staker.publicKey,
@@ -471,10 +464,8 @@ export async function unstakeTokens(amount: number, userKeypair?: Keypair) {
       tokenProgram: TOKEN_2022_PROGRAM_ID,
     };
 
-    // Add governance account if it exists
-    if (hasGovernanceAccount) {
-      accounts.governanceAccount = governanceAccount;
-    }
+    // Governance account is always required (it may or may not exist)
+    accounts.governanceAccount = governanceAccount;
 
     const tx = await program.methods
       .unstake(new anchor.BN(amount * Math.pow(10, 6)))
@@ -549,6 +540,57 @@ export async function initializeGovernanceAccount(userKeypair?: Keypair) {
   }
 }
 
+// === Migrate Governance Account ===
+// Accounts created before the proposal tracking and pledge fields were added are
+// shorter than the current layout and can't be fetched until they are migrated.
+const GOVERNANCE_ACCOUNT_SPACE = 8 + 98;
+
+export async function migrateGovernanceAccount(userKeypair?: Keypair) {
+  const { program, connection, adminKeypair } = getProgram();
+  const staker = userKeypair || adminKeypair;
+
+  const [governanceAccount] = getGovernancePda(program.programId, staker.publicKey);
+
+  try {
+    const accountInfo = await connection.getAccountInfo(governanceAccount);
+    if (!accountInfo) {
+      throw new Error("Governance account does not exist. Initialize it first.");
+    }
+    if (accountInfo.data.length >= GOVERNANCE_ACCOUNT_SPACE) {
+      console.log("Governance account already uses the current layout");
+      return {
+        success: true,
+        message: "Governance account already uses the current layout",
+        governanceAccount: governanceAccount,
+        userPublicKey: staker.publicKey,
+      };
+    }
+
+    const tx = await program.methods
+      .migrateGovernanceAccount()
+      .accounts({
+        staker: staker.publicKey,
+        governanceAccount: governanceAccount,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([staker])
+      .rpc();
+
+    console.log(`Governance account migrated from ${accountInfo.data.length} to ${GOVERNANCE_ACCOUNT_SPACE} bytes`);
+    console.log(`Transaction: ${tx}`);
+
+    return {
+      success: true,
+      transactionId: tx,
+      governanceAccount: governanceAccount,
+      userPublicKey: staker.publicKey,
+    };
+  } catch (error) {
+    console.error("Error migrating governance account:", error);
+    throw error;
+  }
+}
+
 // === Calculate Voting Power ===
 export async function calculateVotingPower(userKeypair?: Keypair) {
   const { program, adminKeypair } = getProgram();
@@ -1021,10 +1063,11 @@ export async function createProposal(
   proposalId: number,
   title: string,
   description: string,
-  proposalType: number, // 0=Text, 1=TreasuryTransfer, 2=ParameterUpdate
+  proposalType: number, // 0=Text, 1=TreasuryTransfer, 2=ParameterUpdate, 3=CustomInstructions, 4=Emergency
   executionData: number[], // Array of bytes
-  votingPeriod: number, // 0 (short), 1 (medium), or 2 (long)
-  userKeypair?: Keypair
+  votingPeriodHours: number, // within the config's min/max voting period hours
+  userKeypair?: Keypair,
+  optimistic: boolean = false // passes unless objected to (text and small treasury proposals only)
 ) {
   const { program, connection, adminKeypair } = getProgram();
   const proposer = userKeypair || adminKeypair;
@@ -1036,6 +1079,7 @@ export async function createProposal(
   const [programAuthority] = getProgramAuthorityPda(program.programId);
   const [proposerStakingAccount] = getUserStakePda(program.programId, proposer.publicKey);
   const [proposerGovernanceAccount] = getGovernancePda(program.programId, proposer.publicKey);
+  const [governanceConfig] = getGovernanceConfigPda(program.programId);
   const [proposalAccount] = getProposalPda(program.programId, proposalId);
   const [depositEscrowAccount] = getProposalEscrowPda(program.programId);
 
@@ -1097,8 +1141,8 @@ export async function createProposal(
   if (executionData.length > 500) {
     throw new Error("Execution data too large (max 500 bytes)");
   }
-  if (![0, 1, 2].includes(votingPeriod)) {
-    throw new Error("Voting period must be 0 (short), 1 (medium), or 2 (long)");
+  if (!Number.isInteger(votingPeriodHours) || votingPeriodHours <= 0) {
+    throw new Error("Voting period must be a positive whole number of hours");
   }
 
   console.log("All validations passed, creating proposal...");
@@ -1108,21 +1152,26 @@ export async function createProposal(
       .createProposal(
         new anchor.BN(proposalId),
         title,
-        description,
+        { inline: { description } }, // ProposalContent - full description stored on-chain
         getProposalTypeEnum(proposalType), // ProposalType enum - adjust based on proposalType param
         Buffer.from(executionData),
-        votingPeriod
+        votingPeriodHours,
+        optimistic,
+        false, // draft - start voting immediately
+        false // privateVoting - public tally
       )
       .accounts({
         proposer: proposer.publicKey,
         proposerStakingAccount: proposerStakingAccount,
         proposerGovernanceAccount: proposerGovernanceAccount,
+        governanceConfig: governanceConfig,
         proposalAccount: proposalAccount,
         stakingPool: stakingPool,
         programAuthority: programAuthority,
         proposerTokenAccount: proposerTokenAccount,
         depositEscrowAccount: depositEscrowAccount,
         depositTokenMint: tokenMintAddress,
+        proposalInstructions: null, // only custom-instruction proposals attach staged instructions
         systemProgram: SystemProgram.programId,
         tokenProgram: TOKEN_2022_PROGRAM_ID,
       })
@@ -1154,7 +1203,7 @@ export interface ProposalInfo {
   proposalType: any;                 // ProposalType (enum, type depends on IDL)
   status: any;                       // ProposalStatus (enum, type depends on IDL)
   executionData: number[];           // Vec<u8>
-  votingPeriodDays: number;          // u8
+  votingPeriodHours: number;         // u32
   createdAt: number;                 // i64 (unix timestamp)
   votingEndsAt: number;              // i64 (unix timestamp)
   finalizedAt: number;               // i64 (unix timestamp)
@@ -1187,7 +1236,7 @@ export async function getProposalInfo(proposalId: number) {
     console.log(`Type: ${JSON.stringify(proposalData.proposalType)}`);
     console.log(`Created: ${new Date(Number(proposalData.createdAt) * 1000)}`);
     console.log(`Voting Ends: ${new Date(Number(proposalData.votingEndsAt) * 1000)}`);
-    console.log(`Voting Period: ${proposalData.votingPeriodDays} days`);
+    console.log(`Voting Period: ${proposalData.votingPeriodHours} hours`);
     console.log(`Yes Votes: ${proposalData.yesVotes.toString()}`);
     console.log(`No Votes: ${proposalData.noVotes.toString()}`);
     console.log(`Abstain Votes: ${proposalData.abstainVotes.toString()}`);
@@ -1203,7 +1252,7 @@ export async function getProposalInfo(proposalId: number) {
       proposer: proposalData.proposer.toString(),
       proposalType: proposalData.proposalType,
       status: proposalData.status,
-      votingPeriodDays: proposalData.votingPeriodDays,
+      votingPeriodHours: proposalData.votingPeriodHours,
       createdAt: Number(proposalData.createdAt),
       votingEndsAt: Number(proposalData.votingEndsAt),
       finalizedAt: Number(proposalData.finalizedAt),
@@ -1248,7 +1297,7 @@ export async function getAllProposals(maxProposalId: number = 10) {
         status: status, // 👈 CHANGE to string
         proposalType: proposalType, // 👈 ADD
         executionData: Array.from(proposalData.executionData || []), // 👈 ADD - Convert Buffer to array
-        votingPeriodDays: proposalData.votingPeriodDays, // 👈 ADD
+        votingPeriodHours: proposalData.votingPeriodHours, // 👈 ADD
         createdAt: Number(proposalData.createdAt), // 👈 ADD
         votingEndsAt: Number(proposalData.votingEndsAt),
         finalizedAt: Number(proposalData.finalizedAt), // 👈 ADD
@@ -1292,6 +1341,8 @@ export async function castVote(
     const [governanceAccount] = getGovernancePda(program.programId, voter.publicKey);
     const [proposalAccount] = getProposalPda(program.programId, proposalId);
     const [voteRecord] = getVoteRecordPda(program.programId, proposalId, voter.publicKey);
+    const [governanceConfig] = getGovernanceConfigPda(program.programId);
+    const [stakingPool] = getStakingPoolPda(program.programId);
 
       // Pre-flight checks
   try {
@@ -1375,6 +1426,9 @@ export async function castVote(
     case VoteChoice.Abstain:
       voteChoiceAnchor = { abstain: {} };
       break;
+    case VoteChoice.NoWithVeto:
+      voteChoiceAnchor = { noWithVeto: {} };
+      break;
     default:
       throw new Error("Invalid vote choice");
   }
@@ -1390,6 +1444,8 @@ export async function castVote(
         governanceAccount: governanceAccount,
         proposalAccount: proposalAccount,
         voteRecord: voteRecord,
+        governanceConfig: governanceConfig,
+        stakingPool: stakingPool,
         systemProgram: SystemProgram.programId,
       })
       .signers([voter])
@@ -1433,7 +1489,8 @@ export async function getVoteRecord(
   try {
     const voteData = await program.account.voteRecord.fetch(voteRecord) as VoteRecord;
     
-    const voteChoice = Object.keys(voteData.voteChoice)[0];
+    // Private votes have no choice until they are revealed
+    const voteChoice = voteData.voteChoice ? Object.keys(voteData.voteChoice)[0] : 'unrevealed';
     
     console.log(`=== Vote Record for Proposal #${proposalId} ===`);
     console.log(`Voter: ${voteData.voter.toString()}`);
@@ -2073,10 +2130,83 @@ export async function initializeGovernanceConfig(userKeypair?: Keypair) {
 // EXECUTION DATA BUILDERS
 // ============================================================================
 
+// Execution data layout: [version, ...borsh(ExecutionPayload)] (see ExecutionPayload in lib.rs)
+export const EXECUTION_PAYLOAD_VERSION = 1;
+
+export enum ExecutionPayloadVariant {
+  Text = 0,
+  TreasuryTransfer = 1,
+  ParameterUpdate = 2,
+  CustomInstructions = 3,
+  Emergency = 4,
+}
+
+type BorshInt = "u8" | "u16" | "u32" | "u64";
+
+const BORSH_INT_SIZES: Record<BorshInt, number> = { u8: 1, u16: 2, u32: 4, u64: 8 };
+
+// ParameterUpdateType variants in lib.rs order; the array index is the borsh variant.
+// Percentages are basis points (10000 = 100%). Enum fields (mode, proposalType,
+// abstainPolicy) take their numeric value, and typeRules flattens ProposalTypeRules.
+export const PARAMETER_UPDATE_LAYOUTS: { type: string; name: string; fields: [string, BorshInt][] }[] = [
+  { type: "quorumThreshold", name: "Quorum Threshold", fields: [["percentage", "u16"]] },
+  { type: "proposalStake", name: "Proposal Stake", fields: [["amount", "u64"]] },
+  { type: "timeMultiplier", name: "Time Multiplier", fields: [["tier", "u8"], ["multiplier", "u16"]] },
+  { type: "votingPeriod", name: "Voting Period", fields: [["hours", "u32"]] },
+  { type: "emergencySettings", name: "Emergency Settings", fields: [["quorumReduction", "u16"], ["votingPeriod", "u32"]] },
+  { type: "treasuryLimit", name: "Treasury Limit", fields: [["percentage", "u16"]] },
+  { type: "executionDelay", name: "Execution Delay", fields: [["hours", "u32"]] },
+  { type: "passingThreshold", name: "Passing Threshold", fields: [["percentage", "u16"]] },
+  { type: "spamCooldown", name: "Spam Cooldown", fields: [["hours", "u32"]] },
+  { type: "vetoThreshold", name: "Veto Threshold", fields: [["percentage", "u16"]] },
+  { type: "optimisticObjectionThreshold", name: "Optimistic Objection Threshold", fields: [["percentage", "u16"]] },
+  { type: "proposalThreshold", name: "Proposal Threshold", fields: [["mode", "u8"], ["percentage", "u16"]] },
+  { type: "quorumDecayFloor", name: "Quorum Decay Floor", fields: [["percentage", "u16"]] },
+  { type: "voteExtension", name: "Vote Extension", fields: [["hours", "u32"], ["maxExtensions", "u8"]] },
+  { type: "privateVoting", name: "Private Voting", fields: [["revealPeriodHours", "u32"], ["unrevealedPenaltyHours", "u32"]] },
+  {
+    type: "typeRules",
+    name: "Proposal Type Rules",
+    fields: [
+      ["proposalType", "u8"],
+      ["quorumPercentage", "u16"],
+      ["passingThresholdPercentage", "u16"],
+      ["executionDelayHours", "u32"],
+      ["minVotingPeriodHours", "u32"],
+      ["depositPercentage", "u16"],
+      ["abstainPolicy", "u8"],
+    ],
+  },
+];
+
+// e.g. { type: "quorumThreshold", percentage: 2500 } or { type: "votingPeriod", hours: 72 }
+export type ParameterUpdate = { type: string; [field: string]: string | number };
+
+const isBasisPointField = (field: string) =>
+  field === "quorumReduction" || field.toLowerCase().endsWith("percentage");
+
+function writeBorshInt(out: number[], kind: BorshInt, value: number) {
+  const buffer = Buffer.alloc(BORSH_INT_SIZES[kind]);
+  if (kind === "u64") {
+    buffer.writeBigUInt64LE(BigInt(value));
+  } else {
+    buffer.writeUIntLE(value, 0, BORSH_INT_SIZES[kind]);
+  }
+  out.push(...Array.from(buffer));
+}
+
 export function buildTextExecutionData(metadata?: string): number[] {
-  if (!metadata) return [];
-  const encoder = new TextEncoder();
-  return Array.from(encoder.encode(metadata));
+  const metadataBytes = metadata ? Array.from(new TextEncoder().encode(metadata)) : [];
+
+  const lengthBuffer = Buffer.alloc(4);
+  lengthBuffer.writeUInt32LE(metadataBytes.length);
+
+  return [
+    EXECUTION_PAYLOAD_VERSION,
+    ExecutionPayloadVariant.Text,
+    ...Array.from(lengthBuffer),
+    ...metadataBytes,
+  ];
 }
 
 export function buildTreasuryTransferExecutionData(
@@ -2097,34 +2227,45 @@ export function buildTreasuryTransferExecutionData(
   amountBuffer.writeBigUInt64LE(BigInt(amountMicroTokens));
   const amountBytes = Array.from(amountBuffer);
 
-  return [...recipientBytes, ...amountBytes];
+  return [
+    EXECUTION_PAYLOAD_VERSION,
+    ExecutionPayloadVariant.TreasuryTransfer,
+    ...recipientBytes,
+    ...amountBytes,
+  ];
 }
 
-export function buildParameterUpdateExecutionData(
-  parameterId: number,
-  newValue: number
-): number[] {
-  if (![0, 1, 2].includes(parameterId)) {
-    throw new Error("Parameter ID must be 0 (Quorum), 1 (Threshold), or 2 (Timelock)");
+// Range checks against the governance bounds happen on-chain when the proposal is created
+export function buildParameterUpdateExecutionData(update: ParameterUpdate): number[] {
+  const variant = PARAMETER_UPDATE_LAYOUTS.findIndex((layout) => layout.type === update?.type);
+  if (variant < 0) {
+    throw new Error(
+      `Unknown parameter update type: ${update?.type}. ` +
+      `Expected one of: ${PARAMETER_UPDATE_LAYOUTS.map((layout) => layout.type).join(", ")}`
+    );
   }
 
-  // Validate ranges
-  if (parameterId === 0 && (newValue < 1 || newValue > 100)) {
-    throw new Error("Quorum percentage must be between 1 and 100");
-  }
-  if (parameterId === 1 && (newValue < 51 || newValue > 100)) {
-    throw new Error("Passing threshold must be between 51 and 100");
-  }
-  if (parameterId === 2 && newValue > 30 * 86400) {
-    throw new Error("Timelock duration must be <= 30 days (2592000 seconds)");
+  const data = [EXECUTION_PAYLOAD_VERSION, ExecutionPayloadVariant.ParameterUpdate, variant];
+  for (const [field, kind] of PARAMETER_UPDATE_LAYOUTS[variant].fields) {
+    const value = Number(update[field]);
+    if (!Number.isSafeInteger(value) || value < 0) {
+      throw new Error(`${update.type}.${field} must be a non-negative integer`);
+    }
+    if (isBasisPointField(field) && value > 10_000) {
+      throw new Error(`${update.type}.${field} is in basis points and must be at most 10000 (100%)`);
+    }
+    writeBorshInt(data, kind, value);
   }
 
-  const parameterIdByte = [parameterId];
-  const valueBuffer = Buffer.alloc(8);
-  valueBuffer.writeBigUInt64LE(BigInt(newValue));
-  const valueBytes = Array.from(valueBuffer);
+  return data;
+}
 
-  return [...parameterIdByte, ...valueBytes];
+export function buildCustomInstructionsExecutionData(): number[] {
+  return [EXECUTION_PAYLOAD_VERSION, ExecutionPayloadVariant.CustomInstructions];
+}
+
+export function buildEmergencyExecutionData(): number[] {
+  return [EXECUTION_PAYLOAD_VERSION, ExecutionPayloadVariant.Emergency];
 }
 
 // ============================================================================
@@ -2628,15 +2769,30 @@ export async function getTreasuryAccount() {
 // DECODE EXECUTION DATA (for previewing what a proposal will do)
 // ============================================================================
 
+function readExecutionPayloadBody(
+  executionData: number[],
+  variant: ExecutionPayloadVariant
+): number[] {
+  if (executionData[0] !== EXECUTION_PAYLOAD_VERSION) {
+    throw new Error(`Unsupported execution payload version: ${executionData[0]}`);
+  }
+  if (executionData[1] !== variant) {
+    throw new Error(`Unexpected execution payload variant: ${executionData[1]}`);
+  }
+
+  return executionData.slice(2);
+}
+
 export function decodeTreasuryTransferExecutionData(executionData: number[]) {
-  if (executionData.length !== 40) {
+  const body = readExecutionPayloadBody(executionData, ExecutionPayloadVariant.TreasuryTransfer);
+  if (body.length !== 40) {
     throw new Error(`Invalid treasury transfer data length: ${executionData.length}`);
   }
 
-  const recipientBytes = executionData.slice(0, 32);
+  const recipientBytes = body.slice(0, 32);
   const recipient = new PublicKey(Buffer.from(recipientBytes));
 
-  const amountBytes = Buffer.from(executionData.slice(32, 40));
+  const amountBytes = Buffer.from(body.slice(32, 40));
   const amount = Number(amountBytes.readBigUInt64LE(0));
 
   return {
@@ -2647,25 +2803,32 @@ export function decodeTreasuryTransferExecutionData(executionData: number[]) {
 }
 
 export function decodeParameterUpdateExecutionData(executionData: number[]) {
-  if (executionData.length !== 9) {
-    throw new Error(`Invalid parameter update data length: ${executionData.length}`);
+  const body = readExecutionPayloadBody(executionData, ExecutionPayloadVariant.ParameterUpdate);
+  const parameterId = body[0];
+  const layout = PARAMETER_UPDATE_LAYOUTS[parameterId];
+  if (!layout) {
+    throw new Error(`Unknown parameter update variant: ${parameterId}`);
   }
 
-  const parameterId = executionData[0];
-  const valueBytes = Buffer.from(executionData.slice(1, 9));
-  const value = Number(valueBytes.readBigUInt64LE(0));
+  const fieldsLength = layout.fields.reduce((total, [, kind]) => total + BORSH_INT_SIZES[kind], 0);
+  if (body.length !== 1 + fieldsLength) {
+    throw new Error(`Invalid parameter update data length: ${executionData.length}`);
+  }
 
-  const paramNames = [
-    "Quorum Percentage",
-    "Passing Threshold",
-    "Timelock Duration (seconds)",
-  ];
-  const paramName = paramNames[parameterId] || "Unknown";
+  const bytes = Buffer.from(body);
+  const update: ParameterUpdate = { type: layout.type };
+  let offset = 1;
+  for (const [field, kind] of layout.fields) {
+    update[field] = kind === "u64"
+      ? Number(bytes.readBigUInt64LE(offset))
+      : bytes.readUIntLE(offset, BORSH_INT_SIZES[kind]);
+    offset += BORSH_INT_SIZES[kind];
+  }
 
   return {
     parameterId,
-    parameterName: paramName,
-    newValue: value,
+    parameterName: layout.name,
+    update,
   };
 }
 
@@ -2694,7 +2857,7 @@ export async function getProposalExecutionPreview(proposalId: number) {
       preview.action = {
         type: 'Update governance parameter',
         parameter: decoded.parameterName,
-        newValue: decoded.newValue,
+        update: decoded.update,
       };
     } else if (proposalType === 'text') {
       preview.action = {
@@ -16,6 +16,7 @@ import {
 } from "@solana/spl-token";
 
 import { StakingPool, UserStakingAccount, GovernanceAccount, VoteRecord, VoteChoice, ProposalInfo, GovernanceConfig } from "../types";
+import { decodeParameterUpdateExecutionData } from "./services";
 
 // Helper function to get the program
 export const getProgram = () => {
@@ -150,6 +151,8 @@ function getProposalTypeEnum(proposalType: number) {
     case 0: return { text: {} };
     case 1: return { treasuryTransfer: {} };
     case 2: return { parameterUpdate: {} };
+    case 3: return { customInstructions: {} };
+    case 4: return { emergency: {} };
     default: throw new Error(`Invalid proposal type: ${proposalType}`);
   }
 }
@@ -315,7 +318,7 @@ export async function createUnstakeTokensTransaction(userPublicKey: string, amou
       tokenProgram: TOKEN_2022_PROGRAM_ID,
     };
 
-    // Add governance account (it may or may not exist)
+    // Governance account is always required (it may or may not exist)
     accounts.governanceAccount = governanceAccount;
 
     const transaction = await program.methods
@@ -486,33 +489,39 @@ export async function createProposalTransaction(
   description: string,
   proposalType: number,
   executionData: number[],
-  votingPeriod: number
+  votingPeriod: number,
+  optimistic: boolean = false
 ) {
 
     // ✅ ADD VALIDATION HERE
-    const proposalTypeName = ['text', 'treasuryTransfer', 'parameterUpdate'][proposalType];
+    const proposalTypeName = ['text', 'treasuryTransfer', 'parameterUpdate', 'customInstructions', 'emergency'][proposalType];
   
     // Validate execution data length based on proposal type
-    if (proposalType === 0) { // Text proposal
-      if (executionData.length !== 0) {
-        throw new Error(`Text proposals must have empty executionData (got ${executionData.length} bytes)`);
-      }
-    } else if (proposalType === 1) { // Treasury transfer
-      if (executionData.length !== 40) {
+    // executionData is [version, ...borsh(ExecutionPayload)] (see ExecutionPayload in lib.rs)
+    if (executionData[0] !== 1 || executionData[1] !== proposalType) {
+      throw new Error(
+        `executionData must start with payload version 1 and variant ${proposalType}. ` +
+        `Use the build*ExecutionData() helpers to create proper execution data.`
+      );
+    }
+    if (proposalType === 1) { // Treasury transfer
+      if (executionData.length !== 42) {
         throw new Error(
-          `Treasury transfer proposals require exactly 40 bytes of executionData (32 bytes recipient + 8 bytes amount). Got ${executionData.length} bytes. ` +
+          `Treasury transfer proposals require exactly 42 bytes of executionData (2 bytes header + 32 bytes recipient + 8 bytes amount). Got ${executionData.length} bytes. ` +
           `Use buildTreasuryTransferExecutionData() to create proper execution data.`
         );
       }
     } else if (proposalType === 2) { // Parameter update
-      if (executionData.length !== 9) {
+      try {
+        decodeParameterUpdateExecutionData(executionData);
+      } catch (error: any) {
         throw new Error(
-          `Parameter update proposals require exactly 9 bytes of executionData (1 byte parameter ID + 8 bytes value). Got ${executionData.length} bytes. ` +
+          `Invalid parameter update executionData: ${error.message}. ` +
           `Use buildParameterUpdateExecutionData() to create proper execution data.`
         );
       }
     }
-  
+
     console.log(`✅ Validation passed: ${proposalTypeName} proposal with ${executionData.length} bytes execution data`);
   
   const { program, connection } = getProgram();
@@ -523,6 +532,7 @@ export async function createProposalTransaction(
   const [programAuthority] = getProgramAuthorityPda(program.programId);
   const [proposerStakingAccount] = getUserStakePda(program.programId, userPubKey);
   const [proposerGovernanceAccount] = getGovernancePda(program.programId, userPubKey);
+  const [governanceConfig] = getGovernanceConfigPda(program.programId);
   const [proposalAccount] = getProposalPda(program.programId, proposalId);
   const [depositEscrowAccount] = getProposalEscrowPda(program.programId);
 
@@ -547,21 +557,26 @@ export async function createProposalTransaction(
       .createProposal(
         new anchor.BN(proposalId),
         title,
-        description,
+        { inline: { description } },
         getProposalTypeEnum(proposalType),
         Buffer.from(executionData),
-        votingPeriod
+        votingPeriod,
+        optimistic,
+        false, // draft - start voting immediately
+        false // privateVoting - public tally
       )
       .accounts({
         proposer: userPubKey,
         proposerStakingAccount: proposerStakingAccount,
         proposerGovernanceAccount: proposerGovernanceAccount,
+        governanceConfig: governanceConfig,
         proposalAccount: proposalAccount,
         stakingPool: stakingPool,
         programAuthority: programAuthority,
         proposerTokenAccount: proposerTokenAccount,
         depositEscrowAccount: depositEscrowAccount,
         depositTokenMint: tokenMintAddress,
+        proposalInstructions: null, // only custom-instruction proposals attach staged instructions
         systemProgram: SystemProgram.programId,
         tokenProgram: TOKEN_2022_PROGRAM_ID,
       })
@@ -604,6 +619,8 @@ export async function createCastVoteTransaction(
   const [governanceAccount] = getGovernancePda(program.programId, userPubKey);
   const [proposalAccount] = getProposalPda(program.programId, proposalId);
   const [voteRecord] = getVoteRecordPda(program.programId, proposalId, userPubKey);
+  const [governanceConfig] = getGovernanceConfigPda(program.programId);
+  const [stakingPool] = getStakingPoolPda(program.programId);
 
   // Convert VoteChoice enum to the format expected by Anchor
   let voteChoiceAnchor: any;
@@ -617,6 +634,9 @@ export async function createCastVoteTransaction(
     case VoteChoice.Abstain:
       voteChoiceAnchor = { abstain: {} };
       break;
+    case VoteChoice.NoWithVeto:
+      voteChoiceAnchor = { noWithVeto: {} };
+      break;
     default:
       throw new Error("Invalid vote choice");
   }
@@ -632,6 +652,8 @@ export async function createCastVoteTransaction(
         governanceAccount: governanceAccount,
         proposalAccount: proposalAccount,
         voteRecord: voteRecord,
+        governanceConfig: governanceConfig,
+        stakingPool: stakingPool,
         systemProgram: SystemProgram.programId,
       })
       .transaction();
@@ -756,7 +778,7 @@ export async function getProposalInfo(proposalId: number) {
         proposalType: proposalData.proposalType,
         status: proposalData.status,
         executionData: proposalData.executionData, 
-        votingPeriodDays: proposalData.votingPeriodDays,
+        votingPeriodHours: proposalData.votingPeriodHours,
         createdAt: Number(proposalData.createdAt),
         votingEndsAt: Number(proposalData.votingEndsAt),
         finalizedAt: Number(proposalData.finalizedAt),
@@ -801,7 +823,7 @@ export async function getAllProposals(maxProposalId: number = 10) {
         status: status, // 👈 CHANGE to string
         proposalType: proposalType, // 👈 ADD
         executionData: Array.from(proposalData.executionData || []), // 👈 ADD - Convert Buffer to array
-        votingPeriodDays: proposalData.votingPeriodDays, // 👈 ADD
+        votingPeriodHours: proposalData.votingPeriodHours, // 👈 ADD
         createdAt: Number(proposalData.createdAt), // 👈 ADD
         votingEndsAt: Number(proposalData.votingEndsAt),
         finalizedAt: Number(proposalData.finalizedAt), // 👈 ADD
@@ -834,7 +856,7 @@ interface ProposalAccount {
   proposer: string;
   status: string;
   proposalType: string;
-  votingPeriodDays: number;
+  votingPeriodHours: number;
   createdAt: number;
   votingEndsAt: number;
   finalizedAt: number;
@@ -878,7 +900,7 @@ export async function getAllProposalsUsingGPA(): Promise<GetAllProposalsResponse
         status: Object.keys(proposalData.status)[0],
         proposalType: Object.keys(proposalData.proposalType)[0],
         executionData: Array.from(proposalData.executionData || []), // 👈 HERE IT IS!
-        votingPeriodDays: proposalData.votingPeriodDays,
+        votingPeriodHours: proposalData.votingPeriodHours,
         createdAt: Number(proposalData.createdAt),
         votingEndsAt: Number(proposalData.votingEndsAt),
         finalizedAt: Number(proposalData.finalizedAt),
@@ -915,7 +937,8 @@ export async function getVoteRecord(proposalId: number, userPublicKey: string) {
   try {
     const voteData = await program.account.voteRecord.fetch(voteRecord) as VoteRecord;
     
-    const voteChoice = Object.keys(voteData.voteChoice)[0];
+    // Private votes have no choice until they are revealed
+    const voteChoice = voteData.voteChoice ? Object.keys(voteData.voteChoice)[0] : 'unrevealed';
     
     return {
       success: true,
@@ -1582,58 +1605,15 @@ export async function getTreasuryAccountInterface() {
 }
 
 // === Execution Data Builders (Client-side helpers) ===
-export function buildTextExecutionData(metadata?: string): number[] {
-  if (!metadata) return [];
-  const encoder = new TextEncoder();
-  return Array.from(encoder.encode(metadata));
-}
-
-export function buildTreasuryTransferExecutionData(
-  recipientAddress: string,
-  amountTokens: number
-): number[] {
-  if (!recipientAddress || amountTokens <= 0) {
-    throw new Error("Invalid treasury transfer parameters");
-  }
-
-  // Convert regular token amount to microtokens (multiply by 1,000,000)
-  const amountMicroTokens = Math.floor(amountTokens * 1_000_000);
-
-  const recipientPubkey = new PublicKey(recipientAddress);
-  const recipientBytes = Array.from(recipientPubkey.toBytes());
-
-  const amountBuffer = Buffer.alloc(8);
-  amountBuffer.writeBigUInt64LE(BigInt(amountMicroTokens));
-  const amountBytes = Array.from(amountBuffer);
-
-  return [...recipientBytes, ...amountBytes];
-}
-
-export function buildParameterUpdateExecutionData(
-  parameterId: number,
-  newValue: number
-): number[] {
-  if (![0, 1, 2].includes(parameterId)) {
-    throw new Error("Parameter ID must be 0 (Quorum), 1 (Threshold), or 2 (Timelock)");
-  }
-
-  if (parameterId === 0 && (newValue < 1 || newValue > 100)) {
-    throw new Error("Quorum percentage must be between 1 and 100");
-  }
-  if (parameterId === 1 && (newValue < 51 || newValue > 100)) {
-    throw new Error("Passing threshold must be between 51 and 100");
-  }
-  if (parameterId === 2 && newValue > 30 * 86400) {
-    throw new Error("Timelock duration must be <= 30 days (2592000 seconds)");
-  }
-
-  const parameterIdByte = [parameterId];
-  const valueBuffer = Buffer.alloc(8);
-  valueBuffer.writeBigUInt64LE(BigInt(newValue));
-  const valueBytes = Array.from(valueBuffer);
-
-  return [...parameterIdByte, ...valueBytes];
-}
+// Shared with services.ts so both produce the versioned ExecutionPayload layout
+export {
+  buildTextExecutionData,
+  buildTreasuryTransferExecutionData,
+  buildParameterUpdateExecutionData,
+  buildCustomInstructionsExecutionData,
+  buildEmergencyExecutionData,
+} from "./services";
+export type { ParameterUpdate } from "./services";
 
 // === Get Governance Config ===
 export async function getGovernanceConfig() {
@@ -94,6 +94,7 @@ export enum VoteChoice {
   Yes = 0,
   No = 1,
   Abstain = 2,
+  NoWithVeto = 3,
 }
 
 // Types for governance accounts
@@ -110,7 +111,7 @@ export interface GovernanceAccount {
 export interface VoteRecord {
   voter: PublicKey;            // 32 bytes
   proposalId: BN;       // 8 bytes
-  voteChoice: VoteChoice;      // 1 byte  
+  voteChoice: VoteChoice | null; // 2 bytes, null until a private vote is revealed
   votingPower: BN;      // 8 bytes
   votedAt: BN;          // 8 bytes
   bump: number;                // 1 byte
@@ -124,7 +125,7 @@ export interface ProposalInfo {
   proposalType: any;                 // ProposalType (enum, type depends on IDL)
   status: any;                       // ProposalStatus (enum, type depends on IDL)
   executionData: number[];           // Vec<u8>
-  votingPeriodDays: number;          // u8
+  votingPeriodHours: number;         // u32
   createdAt: number;                 // i64 (unix timestamp)
   votingEndsAt: number;              // i64 (unix timestamp)
   finalizedAt: number;               // i64 (unix timestamp)